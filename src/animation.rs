@@ -0,0 +1,227 @@
+// src/animation.rs
+
+use std::sync::Arc;
+use bevy::{prelude::*, ecs::query::BatchingStrategy, sprite::Anchor};
+use serde::Deserialize;
+use crate::{
+    AppState,
+    atlas::SpriteAtlas,
+    speed::gameplay_running,
+    core::{ProjectileHit, TowerFired},
+    tower::TowerType,
+    constants::{enemies as EnemyConstants, projectiles as ProjectileConstants},
+};
+
+// --- Description des animations ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum AnimationMode {
+    #[default]
+    Once,           // Joue une fois et reste sur la dernière frame
+    Loop,           // Boucle à l'infini
+    OnceThenDespawn // Joue une fois puis supprime l'entité (effets)
+}
+
+// Une frame : index dans l'atlas + décalage visuel (en fraction de la taille du sprite)
+// Le décalage est exprimé dans le repère du tir : x = vers la cible, y = perpendiculaire
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationFrame {
+    pub index: usize,
+    pub offset: Vec2,
+}
+
+// Les clips sont décrits dans le fichier de l'atlas (voir atlas.rs)
+#[derive(Debug, Default)]
+pub struct AnimationClip {
+    pub frames: Vec<AnimationFrame>,
+    pub frame_time: f32, // secondes par frame
+    pub mode: AnimationMode,
+}
+
+impl AnimationClip {
+    fn is_finished(&self, frame: usize, elapsed: f32) -> bool {
+        self.mode != AnimationMode::Loop && frame + 1 >= self.frames.len() && elapsed >= self.frame_time
+    }
+}
+
+// Noms des clips du jeu dans l'atlas
+pub mod clips {
+    use super::*;
+
+    // Recul des tours
+    pub fn tower_fire(tower_type: TowerType) -> &'static str {
+        match tower_type {
+            TowerType::Canon => "canon_fire",
+            TowerType::Archer => "archer_fire",
+            TowerType::Wizard => "wizard_fire",
+        }
+    }
+
+    // Cycles de marche
+    pub fn walk(enemy_type: u32) -> &'static str {
+        match enemy_type {
+            EnemyConstants::BAT => "bat_fly",
+            EnemyConstants::KNIGHT => "knight_walk",
+            EnemyConstants::WOLF => "wolf_run",
+            _ => "orc_walk",
+        }
+    }
+
+    pub fn impact(projectile_type: u32) -> Option<&'static str> {
+        match projectile_type {
+            ProjectileConstants::BOMB => Some("explosion"),
+            ProjectileConstants::CHAINS => Some("magic_hit"),
+            _ => None, // Les flèches n'ont pas d'effet d'impact
+        }
+    }
+}
+
+// --- Composants ---
+
+// Joue un AnimationClip sur le TextureAtlas (et l'ancre) du sprite
+#[derive(Component, Default)]
+pub struct SpriteAnimator {
+    clip: Option<Arc<AnimationClip>>,
+    frame: usize,
+    elapsed: f32,
+    // Direction du repère des décalages (x du clip) dans le repère du sprite
+    direction: Vec2,
+}
+
+impl SpriteAnimator {
+    pub fn playing(clip: Arc<AnimationClip>) -> Self {
+        Self { clip: Some(clip), frame: 0, elapsed: 0.0, direction: Vec2::X }
+    }
+
+    // (Re)lance un clip depuis le début
+    pub fn play(&mut self, clip: Arc<AnimationClip>, direction: Vec2) {
+        self.clip = Some(clip);
+        self.frame = 0;
+        self.elapsed = 0.0;
+        self.direction = direction;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match &self.clip {
+            Some(clip) => clip.is_finished(self.frame, self.elapsed),
+            None => true,
+        }
+    }
+}
+
+// Effet d'impact éphémère (nettoyé en quittant le jeu ou la simulation)
+#[derive(Component)]
+pub struct ImpactEffect;
+
+// Les tours de ce type s'orientent vers leur cible (le canon), les autres se retournent seulement
+pub fn rotates_to_target(tower_type: TowerType) -> bool {
+    tower_type == TowerType::Canon
+}
+
+// --- Plugin ---
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (play_tower_fire, spawn_impact_effects, animate_sprites).chain().run_if(gameplay_running))
+            .add_systems(OnExit(AppState::Playing), cleanup_effects)
+            .add_systems(OnExit(AppState::Simulation), cleanup_effects);
+    }
+}
+
+// --- Systèmes ---
+
+fn animate_sprites(
+    par_commands: ParallelCommands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut SpriteAnimator, &mut TextureAtlas, &mut Sprite)>,
+) {
+    let dt = time.delta_seconds();
+
+    // Des dizaines de milliers d'ennemis en simulation : traitement par lots en parallèle
+    query
+        .par_iter_mut()
+        .batching_strategy(BatchingStrategy::new().min_batch_size(256))
+        .for_each(|(entity, mut animator, mut atlas, mut sprite)| {
+            // Emprunt du clip sans cloner l'Arc (partagé par des milliers d'ennemis)
+            let SpriteAnimator { clip, frame, elapsed, direction } = &mut *animator;
+            let Some(clip) = clip.as_deref() else { return; };
+            if clip.frames.is_empty() || clip.is_finished(*frame, *elapsed) { return; }
+
+            *elapsed += dt;
+            while *elapsed >= clip.frame_time {
+                if *frame + 1 < clip.frames.len() {
+                    *elapsed -= clip.frame_time;
+                    *frame += 1;
+                } else if clip.mode == AnimationMode::Loop {
+                    *elapsed -= clip.frame_time;
+                    *frame = 0;
+                } else {
+                    break;
+                }
+            }
+
+            if clip.is_finished(*frame, *elapsed) && clip.mode == AnimationMode::OnceThenDespawn {
+                par_commands.command_scope(|mut commands| {
+                    commands.entity(entity).despawn_recursive();
+                });
+                return;
+            }
+
+            let current = clip.frames[*frame];
+            if atlas.index != current.index { atlas.index = current.index; }
+            // Passage du repère du clip au repère du sprite, puis décalage -> ancre (inversée)
+            let dir = *direction;
+            let offset = Vec2::new(
+                current.offset.x * dir.x - current.offset.y * dir.y,
+                current.offset.x * dir.y + current.offset.y * dir.x,
+            );
+            let anchor = if offset == Vec2::ZERO { Anchor::Center } else { Anchor::Custom(-offset) };
+            if sprite.anchor != anchor { sprite.anchor = anchor; }
+        });
+}
+
+// Oriente la tour et lance son animation de tir
+fn play_tower_fire(
+    atlas: Res<SpriteAtlas>,
+    mut events: EventReader<TowerFired>,
+    mut towers: Query<(&mut SpriteAnimator, &mut Transform, &mut Sprite)>,
+) {
+    for event in events.read() {
+        let Ok((mut animator, mut transform, mut sprite)) = towers.get_mut(event.tower) else { continue; };
+        if rotates_to_target(event.tower_type) {
+            // Le sprite du canon pointe vers la droite : on le tourne vers la cible
+            transform.rotation = Quat::from_rotation_z(event.direction.y.atan2(event.direction.x));
+            animator.play(atlas.clip(clips::tower_fire(event.tower_type)), Vec2::X);
+        } else {
+            sprite.flip_x = event.direction.x < 0.0;
+            animator.play(atlas.clip(clips::tower_fire(event.tower_type)), event.direction);
+        }
+    }
+}
+
+fn spawn_impact_effects(mut commands: Commands, atlas: Res<SpriteAtlas>, mut hits: EventReader<ProjectileHit>) {
+    for hit in hits.read() {
+        let Some(name) = clips::impact(hit.projectile_type) else { continue; };
+        // Sans frame, l'effet ne se supprimerait jamais
+        let clip = atlas.clip(name);
+        let Some(first) = clip.frames.first() else { continue; };
+        commands.spawn((
+            SpriteSheetBundle {
+                texture: atlas.image.clone(),
+                atlas: TextureAtlas { layout: atlas.layout.clone(), index: first.index },
+                transform: Transform::from_xyz(hit.position.x, hit.position.y, 3.0),
+                ..default()
+            },
+            SpriteAnimator::playing(clip),
+            ImpactEffect,
+        ));
+    }
+}
+
+fn cleanup_effects(mut commands: Commands, query: Query<Entity, With<ImpactEffect>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
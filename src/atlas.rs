@@ -0,0 +1,182 @@
+// src/atlas.rs
+// Atlas de sprites décrit par un fichier (assets/atlas/*.atlas.ron) : l'image, son découpage en grille,
+// le nom de chaque sprite et les animations. Le code demande les sprites et les animations par leur nom,
+// on peut donc changer d'atlas (option "Sprites" : spriteatlas.png ou old_spriteatlas.png) sans toucher au code.
+// Un nom absent de l'atlas donne le premier sprite, une animation absente ne joue rien
+
+use std::{collections::HashMap, sync::Arc};
+use bevy::{
+    prelude::*,
+    asset::{AssetLoader, AsyncReadExt, LoadContext, io::Reader},
+    utils::BoxedFuture,
+};
+use serde::{Serialize, Deserialize};
+use crate::animation::{AnimationClip, AnimationFrame, AnimationMode};
+
+// Atlas choisi dans les options
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AtlasTheme {
+    #[default]
+    Modern,
+    Classic,
+}
+
+impl AtlasTheme {
+    pub fn path(&self) -> &'static str {
+        match self {
+            AtlasTheme::Modern => "atlas/spriteatlas.atlas.ron",
+            AtlasTheme::Classic => "atlas/old_spriteatlas.atlas.ron",
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            AtlasTheme::Modern => "atlas.modern",
+            AtlasTheme::Classic => "atlas.classic",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            AtlasTheme::Modern => AtlasTheme::Classic,
+            AtlasTheme::Classic => AtlasTheme::Modern,
+        }
+    }
+}
+
+// --- Fichier descripteur, tel qu'écrit sur le disque ---
+
+#[derive(Deserialize)]
+struct AtlasFile {
+    image: String,   // Chemin depuis le dossier assets
+    tile_size: f32,  // Sprites carrés, en pixels
+    columns: usize,
+    rows: usize,
+    sprites: HashMap<String, (usize, usize)>, // nom -> (colonne, ligne)
+    #[serde(default)]
+    animations: HashMap<String, AnimationFile>,
+}
+
+#[derive(Deserialize)]
+struct AnimationFile {
+    frame_time: f32,
+    mode: AnimationMode,
+    frames: Vec<FrameFile>,
+}
+
+#[derive(Deserialize)]
+struct FrameFile {
+    sprite: String,
+    #[serde(default)]
+    offset: (f32, f32),
+}
+
+// --- Atlas chargé : noms résolus en index ---
+
+#[derive(Asset, Resource, TypePath, Debug, Clone, Default)]
+pub struct SpriteAtlas {
+    #[dependency]
+    pub image: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    sprites: HashMap<String, usize>,
+    clips: HashMap<String, Arc<AnimationClip>>,
+}
+
+impl SpriteAtlas {
+    pub fn index(&self, name: &str) -> usize {
+        self.sprites.get(name).copied().unwrap_or_default()
+    }
+
+    pub fn sprite(&self, name: &str) -> TextureAtlas {
+        TextureAtlas { layout: self.layout.clone(), index: self.index(name) }
+    }
+
+    pub fn clip(&self, name: &str) -> Arc<AnimationClip> {
+        self.clips.get(name).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+struct SpriteAtlasLoader;
+
+impl AssetLoader for SpriteAtlasLoader {
+    type Asset = SpriteAtlas;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn load<'a>(&'a self, reader: &'a mut Reader, _settings: &'a (), load_context: &'a mut LoadContext) -> BoxedFuture<'a, Result<SpriteAtlas, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let file: AtlasFile = ron::de::from_bytes(&bytes)?;
+
+            let mut sprites = HashMap::new();
+            for (name, (x, y)) in file.sprites {
+                if x >= file.columns || y >= file.rows {
+                    return Err(format!("sprite '{name}' ({x}, {y}) hors de la grille {}x{}", file.columns, file.rows).into());
+                }
+                sprites.insert(name, y * file.columns + x);
+            }
+
+            let mut clips = HashMap::new();
+            for (name, animation) in file.animations {
+                let mut frames = Vec::with_capacity(animation.frames.len());
+                for frame in animation.frames {
+                    let Some(&index) = sprites.get(&frame.sprite) else {
+                        return Err(format!("animation '{name}' : sprite '{}' inconnu", frame.sprite).into());
+                    };
+                    frames.push(AnimationFrame { index, offset: Vec2::new(frame.offset.0, frame.offset.1) });
+                }
+                clips.insert(name, Arc::new(AnimationClip { frames, frame_time: animation.frame_time, mode: animation.mode }));
+            }
+
+            let layout = TextureAtlasLayout::from_grid(Vec2::splat(file.tile_size), file.columns, file.rows, None, None);
+            Ok(SpriteAtlas {
+                image: load_context.load(file.image),
+                layout: load_context.add_labeled_asset("layout".to_string(), layout),
+                sprites,
+                clips,
+            })
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas.ron"]
+    }
+}
+
+// Atlas en cours de chargement ou affiché
+#[derive(Resource, Default)]
+pub struct CurrentAtlas(pub Handle<SpriteAtlas>);
+
+pub struct AtlasPlugin;
+
+impl Plugin for AtlasPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<SpriteAtlas>()
+            .register_asset_loader(SpriteAtlasLoader)
+            .init_resource::<AtlasTheme>()
+            .init_resource::<CurrentAtlas>()
+            .init_resource::<SpriteAtlas>()
+            .add_systems(Update, (load_atlas, refresh_atlas).chain());
+    }
+}
+
+// Charge le descripteur de l'atlas choisi (au lancement puis à chaque changement d'option)
+fn load_atlas(theme: Res<AtlasTheme>, asset_server: Res<AssetServer>, mut current: ResMut<CurrentAtlas>) {
+    if !theme.is_changed() { return; }
+    current.0 = asset_server.load(theme.path());
+}
+
+// Remplace l'atlas utilisé quand le nouveau (image comprise) est prêt, ou quand le fichier est modifié
+fn refresh_atlas(mut commands: Commands, mut events: EventReader<AssetEvent<SpriteAtlas>>, current: Res<CurrentAtlas>, atlases: Res<Assets<SpriteAtlas>>) {
+    for event in events.read() {
+        if let AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } = event
+            && *id == current.0.id()
+            && let Some(atlas) = atlases.get(*id)
+        {
+            commands.insert_resource(atlas.clone());
+        }
+    }
+}
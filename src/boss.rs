@@ -0,0 +1,259 @@
+// src/boss.rs
+// Boss : ennemis normaux (Enemy / Health) en plus grand, avec une barre de vie en haut de
+// l'écran et des capacités sous forme de composants réutilisables sur n'importe quel ennemi
+
+use bevy::prelude::*;
+use serde::{Serialize, Deserialize};
+use crate::{
+    AppState,
+    atlas::SpriteAtlas,
+    speed::gameplay_running,
+    game::GameComponent,
+    difficulty::Difficulty,
+    enemy::{Enemy, Health, PathFollower, base_speed, spawn_enemy},
+    constants::enemies::{self as EnemyConstants, ORC, BAT, KNIGHT},
+    i18n::Localization,
+};
+
+const BOSS_SCALE: f32 = 1.75;
+const BOSS_SPEED_MULTIPLIER: f32 = 0.8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BossKind {
+    Warlord,    // Soigne les ennemis autour de lui
+    BatQueen,   // Libère des chauves-souris quand elle est blessée
+    Juggernaut, // Bouclier temporaire qui arrête les projectiles
+}
+
+impl BossKind {
+    pub const ALL: [BossKind; 3] = [BossKind::Warlord, BossKind::BatQueen, BossKind::Juggernaut];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossKind::Warlord => "Orc Warlord",
+            BossKind::BatQueen => "Bat Queen",
+            BossKind::Juggernaut => "Iron Juggernaut",
+        }
+    }
+
+    // Clé du nom traduit affiché dans la barre de vie (i18n.rs)
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            BossKind::Warlord => "boss.warlord",
+            BossKind::BatQueen => "boss.bat_queen",
+            BossKind::Juggernaut => "boss.juggernaut",
+        }
+    }
+
+    // Type d'ennemi de base (sprite, animation, kills et primes)
+    pub fn enemy_type(&self) -> u32 {
+        match self {
+            BossKind::Warlord => ORC,
+            BossKind::BatQueen => BAT,
+            BossKind::Juggernaut => KNIGHT,
+        }
+    }
+
+    // Vie avant difficulté et croissance des vagues
+    pub fn base_health(&self) -> u32 {
+        match self {
+            BossKind::Warlord => 1500,
+            BossKind::BatQueen => 1000,
+            BossKind::Juggernaut => 2500,
+        }
+    }
+
+    pub fn base_speed(&self) -> f32 {
+        base_speed(self.enemy_type()) * BOSS_SPEED_MULTIPLIER
+    }
+}
+
+#[derive(Component)]
+pub struct Boss {
+    pub kind: BossKind,
+}
+
+// --- Capacités ---
+
+// Soigne régulièrement les autres ennemis dans le rayon
+#[derive(Component)]
+pub struct HealAura {
+    pub radius: f32,
+    pub amount: i32,
+    pub timer: Timer,
+}
+
+// Fait apparaître des sbires à chaque palier de vie perdue (ex. tous les 25 %)
+#[derive(Component)]
+pub struct SpawnOnDamage {
+    pub minion_type: u32,
+    pub count: usize,
+    pub step: f32,           // Fraction de vie entre deux paliers
+    pub next_threshold: f32, // Prochain palier (fraction de la vie max)
+}
+
+impl SpawnOnDamage {
+    // Les paliers déjà franchis (partie rechargée) ne redonnent pas de sbires
+    pub fn new(minion_type: u32, count: usize, step: f32, health: &Health) -> Self {
+        let fraction = health.current as f32 / health.max as f32;
+        let next_threshold = ((fraction / step).ceil() - 1.0) * step;
+        Self { minion_type, count, step, next_threshold }
+    }
+}
+
+// Bouclier actif par intermittence : les projectiles qui touchent sont perdus
+#[derive(Component)]
+pub struct Shield {
+    pub active: bool,
+    pub duration: Timer,
+    pub cooldown: Timer,
+}
+
+impl Shield {
+    pub fn new(duration: f32, cooldown: f32) -> Self {
+        Self {
+            active: false,
+            duration: Timer::from_seconds(duration, TimerMode::Once),
+            cooldown: Timer::from_seconds(cooldown, TimerMode::Once),
+        }
+    }
+}
+
+// Barre de vie du boss en haut de l'écran
+#[derive(Component)]
+struct BossBar;
+#[derive(Component)]
+struct BossBarName;
+#[derive(Component)]
+struct BossBarFill;
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::Playing), setup_boss_bar)
+            .add_systems(Update, (heal_aura, spawn_minions_on_damage, update_shields).run_if(in_state(AppState::Playing).and_then(gameplay_running)))
+            .add_systems(Update, update_boss_bar.run_if(in_state(AppState::Playing)));
+    }
+}
+
+// Crée un boss avec les capacités de son archétype (vagues et chargement de partie)
+pub fn spawn_boss(commands: &mut Commands, atlas: &SpriteAtlas, kind: BossKind, position: Vec2, path_index: usize, health: Health, speed: f32) -> Entity {
+    let entity = spawn_enemy(commands, atlas, kind.enemy_type(), position, path_index, health, speed);
+    let mut boss = commands.entity(entity);
+    boss.insert((
+        Boss { kind },
+        Transform::from_xyz(position.x, position.y, 1.0).with_scale(Vec3::splat(BOSS_SCALE)),
+        Name::new(kind.name()),
+    ));
+    match kind {
+        BossKind::Warlord => { boss.insert(HealAura { radius: 96.0, amount: 20, timer: Timer::from_seconds(3.0, TimerMode::Repeating) }); }
+        BossKind::BatQueen => { boss.insert(SpawnOnDamage::new(BAT, 3, 0.25, &health)); }
+        BossKind::Juggernaut => { boss.insert(Shield::new(2.5, 6.0)); }
+    }
+    entity
+}
+
+fn setup_boss_bar(mut commands: Commands) {
+    commands.spawn((NodeBundle {
+        style: Style { width: Val::Percent(100.0), position_type: PositionType::Absolute, top: Val::Px(8.0), justify_content: JustifyContent::Center, display: Display::None, ..default() },
+        z_index: ZIndex::Global(40), ..default() }, BossBar, GameComponent,
+    )).with_children(|parent| {
+        parent.spawn(NodeBundle { style: Style { width: Val::Px(360.0), flex_direction: FlexDirection::Column, align_items: AlignItems::Center, ..default() }, ..default() })
+            .with_children(|col| {
+                col.spawn((TextBundle::from_section("", TextStyle { font_size: 18.0, color: Color::WHITE, ..default() }), BossBarName));
+                col.spawn(NodeBundle {
+                    style: Style { width: Val::Percent(100.0), height: Val::Px(14.0), border: UiRect::all(Val::Px(2.0)), ..default() },
+                    background_color: Color::BLACK.into(), border_color: BorderColor(Color::BLACK), ..default()
+                }).with_children(|bar| {
+                    bar.spawn((NodeBundle { style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), ..default() }, background_color: Color::RED.into(), ..default() }, BossBarFill));
+                });
+            });
+    });
+}
+
+fn heal_aura(time: Res<Time>, mut healers: Query<(Entity, &Transform, &mut HealAura)>, mut enemies: Query<(Entity, &Transform, &mut Health), With<Enemy>>) {
+    for (healer, transform, mut aura) in healers.iter_mut() {
+        aura.timer.tick(time.delta());
+        if !aura.timer.just_finished() { continue; }
+        let center = transform.translation.truncate();
+        for (entity, enemy_transform, mut health) in enemies.iter_mut() {
+            // Pas lui-même, ni un ennemi déjà mort dans la frame
+            if entity == healer || health.current <= 0 { continue; }
+            if enemy_transform.translation.truncate().distance(center) <= aura.radius {
+                health.current = (health.current + aura.amount).min(health.max);
+            }
+        }
+    }
+}
+
+fn spawn_minions_on_damage(
+    mut commands: Commands,
+    atlas: Res<SpriteAtlas>,
+    difficulty: Res<Difficulty>,
+    mut query: Query<(&Transform, &Health, &PathFollower, &mut SpawnOnDamage)>,
+) {
+    for (transform, health, follower, mut spawner) in query.iter_mut() {
+        if health.current <= 0 { continue; }
+        let fraction = health.current as f32 / health.max as f32;
+        while spawner.next_threshold > 0.0 && fraction <= spawner.next_threshold {
+            spawner.next_threshold -= spawner.step;
+            for i in 0..spawner.count {
+                // Petit décalage pour ne pas empiler les sbires
+                let offset = Vec2::new((i as f32 - (spawner.count - 1) as f32 / 2.0) * 10.0, 0.0);
+                let max = difficulty.enemy_health(EnemyConstants::get_starthealth(spawner.minion_type));
+                let speed = difficulty.enemy_speed(base_speed(spawner.minion_type));
+                spawn_enemy(&mut commands, &atlas, spawner.minion_type, transform.translation.truncate() + offset, follower.path_index, Health { current: max, max }, speed);
+            }
+        }
+    }
+}
+
+// Alterne recharge et bouclier actif ; l'ennemi est teinté en bleu tant que le bouclier tient
+fn update_shields(time: Res<Time>, mut query: Query<(&mut Shield, &mut Sprite)>) {
+    for (mut shield, mut sprite) in query.iter_mut() {
+        if shield.active {
+            shield.duration.tick(time.delta());
+            if shield.duration.finished() {
+                shield.active = false;
+                shield.cooldown.reset();
+            }
+        } else {
+            shield.cooldown.tick(time.delta());
+            if shield.cooldown.finished() {
+                shield.active = true;
+                shield.duration.reset();
+            }
+        }
+        let color = if shield.active { Color::rgb(0.5, 0.7, 1.0) } else { Color::WHITE };
+        if sprite.color != color { sprite.color = color; }
+    }
+}
+
+fn update_boss_bar(
+    bosses: Query<(&Boss, &Health, Option<&Shield>)>,
+    mut root: Query<&mut Style, With<BossBar>>,
+    mut fill: Query<(&mut Style, &mut BackgroundColor), (With<BossBarFill>, Without<BossBar>)>,
+    mut name: Query<&mut Text, With<BossBarName>>,
+    loc: Res<Localization>,
+) {
+    let boss = bosses.iter().next();
+    for mut style in root.iter_mut() {
+        let display = if boss.is_some() { Display::Flex } else { Display::None };
+        if style.display != display { style.display = display; }
+    }
+    let Some((boss, health, shield)) = boss else { return; };
+
+    let percent = (health.current as f32 / health.max as f32).clamp(0.0, 1.0) * 100.0;
+    let shielded = shield.is_some_and(|s| s.active);
+    for (mut style, mut color) in fill.iter_mut() {
+        style.width = Val::Percent(percent);
+        color.0 = if shielded { Color::rgb(0.4, 0.6, 1.0) } else { Color::RED };
+    }
+    for mut text in name.iter_mut() {
+        let boss_name = loc.get(boss.kind.name_key());
+        let value = if shielded { loc.format("boss.shielded", &[&boss_name]) } else { boss_name.to_string() };
+        if text.sections[0].value != value { text.sections[0].value = value; }
+    }
+}
@@ -0,0 +1,153 @@
+// src/camera.rs
+// Caméra 2D : la carte et la barre du bas restent entières et centrées quelle que soit la
+// taille de la fenêtre, et l'interface suit la même échelle que le monde.
+// En partie : zoom à la molette, défilement (clic droit maintenu, bords de l'écran, WASD)
+// sans jamais sortir de la carte
+
+use bevy::{
+    prelude::*,
+    input::mouse::{MouseMotion, MouseWheel, MouseScrollUnit},
+    render::camera::ScalingMode,
+    window::{PrimaryWindow, WindowResized},
+};
+use crate::{
+    AppState,
+    input::{ActionState, InputAction},
+    level::{VIEW_WIDTH, VIEW_HEIGHT},
+};
+
+// Échelle de la projection : 1.0 = toute la zone de jeu visible, plus petit = zoom avant
+const MIN_ZOOM: f32 = 0.35;
+const MAX_ZOOM: f32 = 1.0;
+const ZOOM_STEP: f32 = 0.1; // Par cran de molette
+const PAN_SPEED: f32 = 500.0; // Unités du monde par seconde à l'échelle 1
+const EDGE_SCROLL_MARGIN: f32 = 12.0; // px depuis le bord de la fenêtre
+
+// Caméra principale du jeu (conversions écran -> monde)
+#[derive(Component)]
+pub struct MainCamera;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Startup, setup_camera)
+            .add_systems(OnEnter(AppState::Playing), reset_camera)
+            .add_systems(OnEnter(AppState::Simulation), reset_camera)
+            .add_systems(OnExit(AppState::Playing), reset_camera)
+            .add_systems(OnExit(AppState::Simulation), reset_camera)
+            .add_systems(Update, sync_ui_scale)
+            .add_systems(Update, (zoom_camera, pan_camera, clamp_camera).chain()
+                .run_if(in_state(AppState::Playing).or_else(in_state(AppState::Simulation))));
+    }
+}
+
+/// Lancement de la caméra 2D dans le monde
+fn setup_camera(mut commands: Commands) {
+    let mut camera = Camera2dBundle::default();
+    // Toujours au moins VIEW_WIDTH x VIEW_HEIGHT unités visibles : le surplus de la fenêtre
+    // se répartit autour de la carte
+    camera.projection.scaling_mode = ScalingMode::AutoMin { min_width: VIEW_WIDTH, min_height: VIEW_HEIGHT };
+    commands.spawn((camera, MainCamera));
+}
+
+// Unités du monde par pixel logique (suit ScalingMode::AutoMin et le zoom)
+pub fn units_per_pixel(window: &Window, projection: &OrthographicProjection) -> f32 {
+    (VIEW_WIDTH / window.width()).max(VIEW_HEIGHT / window.height()) * projection.scale
+}
+
+// Taille de la zone visible dans le monde
+pub fn visible_size(window: &Window, projection: &OrthographicProjection) -> Vec2 {
+    Vec2::new(window.width(), window.height()) * units_per_pixel(window, projection)
+}
+
+// Échelle de l'interface alignée sur celle de la caméra : la barre du bas couvre toujours
+// la même bande du monde sous la carte, et les textes grandissent avec la fenêtre
+fn sync_ui_scale(mut resized: EventReader<WindowResized>, windows: Query<&Window, With<PrimaryWindow>>, mut ui_scale: ResMut<UiScale>) {
+    if resized.is_empty() && !ui_scale.is_added() { return; }
+    resized.clear();
+    let Ok(window) = windows.get_single() else { return; };
+    let scale = (window.width() / VIEW_WIDTH).min(window.height() / VIEW_HEIGHT);
+    if scale > 0.0 && ui_scale.0 != scale { ui_scale.0 = scale; }
+}
+
+// Vue d'ensemble en entrant (et en sortant) d'une partie
+fn reset_camera(mut cameras: Query<(&mut Transform, &mut OrthographicProjection), With<MainCamera>>) {
+    for (mut transform, mut projection) in cameras.iter_mut() {
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+        projection.scale = 1.0;
+    }
+}
+
+// Zoom vers le curseur : le point du monde sous la souris ne bouge pas
+fn zoom_camera(
+    mut wheel: EventReader<MouseWheel>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&Camera, &GlobalTransform, &mut Transform, &mut OrthographicProjection), With<MainCamera>>,
+) {
+    let lines: f32 = wheel.read().map(|e| match e.unit {
+        MouseScrollUnit::Line => e.y,
+        MouseScrollUnit::Pixel => e.y / 40.0,
+    }).sum();
+    if lines == 0.0 { return; }
+    let Ok(window) = windows.get_single() else { return; };
+    let Ok((camera, global, mut transform, mut projection)) = cameras.get_single_mut() else { return; };
+
+    let old = projection.scale;
+    let new = (old * (1.0 - ZOOM_STEP).powf(lines)).clamp(MIN_ZOOM, MAX_ZOOM);
+    if new == old { return; }
+    projection.scale = new;
+    if let Some(cursor) = window.cursor_position().and_then(|c| camera.viewport_to_world_2d(global, c)) {
+        let center = transform.translation.truncate();
+        let shifted = center + (cursor - center) * (1.0 - new / old);
+        transform.translation = shifted.extend(transform.translation.z);
+    }
+}
+
+fn pan_camera(
+    time: Res<Time<Real>>,
+    actions: Res<ActionState>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut motion: EventReader<MouseMotion>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Transform, &OrthographicProjection), With<MainCamera>>,
+) {
+    let drag: Vec2 = motion.read().map(|e| e.delta).sum();
+    let Ok(window) = windows.get_single() else { return; };
+    let Ok((mut transform, projection)) = cameras.get_single_mut() else { return; };
+    let scale = units_per_pixel(window, projection);
+
+    // Clic droit maintenu : la carte suit la souris (y de l'écran vers le bas)
+    if mouse.pressed(MouseButton::Right) {
+        transform.translation.x -= drag.x * scale;
+        transform.translation.y += drag.y * scale;
+    }
+
+    let mut direction = Vec2::ZERO;
+    for (action, dir) in [(InputAction::PanUp, Vec2::Y), (InputAction::PanDown, Vec2::NEG_Y), (InputAction::PanLeft, Vec2::NEG_X), (InputAction::PanRight, Vec2::X)] {
+        if actions.pressed(action) { direction += dir; }
+    }
+    // Curseur au bord de la fenêtre
+    if window.focused && let Some(cursor) = window.cursor_position() {
+        if cursor.x < EDGE_SCROLL_MARGIN { direction.x -= 1.0; }
+        if cursor.x > window.width() - EDGE_SCROLL_MARGIN { direction.x += 1.0; }
+        if cursor.y < EDGE_SCROLL_MARGIN { direction.y += 1.0; }
+        if cursor.y > window.height() - EDGE_SCROLL_MARGIN { direction.y -= 1.0; }
+    }
+    let step = direction.normalize_or_zero() * PAN_SPEED * projection.scale * time.delta_seconds();
+    transform.translation += step.extend(0.0);
+}
+
+// La vue reste dans la zone de jeu (carte + bande de la barre du bas) ; centrée si elle la dépasse
+fn clamp_camera(windows: Query<&Window, With<PrimaryWindow>>, mut cameras: Query<(&mut Transform, &OrthographicProjection), With<MainCamera>>) {
+    let Ok(window) = windows.get_single() else { return; };
+    let Ok((mut transform, projection)) = cameras.get_single_mut() else { return; };
+    let half = visible_size(window, projection) / 2.0;
+    let limit = (Vec2::new(VIEW_WIDTH, VIEW_HEIGHT) / 2.0 - half).max(Vec2::ZERO);
+    let clamped = transform.translation.truncate().clamp(-limit, limit);
+    if clamped != transform.translation.truncate() {
+        transform.translation = clamped.extend(transform.translation.z);
+    }
+}
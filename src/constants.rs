@@ -43,7 +43,60 @@ pub mod enemies {
             _ => 0,
         }
     }
-    
+
+    pub fn get_name(enemy_type: u32) -> &'static str {
+        match enemy_type {
+            ORC => "Orc",
+            BAT => "Bat",
+            KNIGHT => "Knight",
+            WOLF => "Wolf",
+            _ => "Unknown",
+        }
+    }
+
+    // Clé du nom traduit (i18n.rs)
+    pub fn get_name_key(enemy_type: u32) -> &'static str {
+        match enemy_type {
+            ORC => "enemy.orc",
+            BAT => "enemy.bat",
+            KNIGHT => "enemy.knight",
+            WOLF => "enemy.wolf",
+            _ => "enemy.unknown",
+        }
+    }
+
+    // Points de score gagnés en tuant l'ennemi
+    pub fn get_score(enemy_type: u32) -> u32 {
+        match enemy_type {
+            ORC => 10,
+            BAT => 15,
+            KNIGHT => 40,
+            WOLF => 20,
+            _ => 0,
+        }
+    }
+
+    // Or gagné en tuant l'ennemi (prime)
+    pub fn get_bounty(enemy_type: u32) -> i32 {
+        match enemy_type {
+            ORC => 5,
+            BAT => 5,
+            KNIGHT => 25,
+            WOLF => 10,
+            _ => 0,
+        }
+    }
+
+    // Nom du sprite de l'ennemi dans l'atlas
+    pub fn get_sprite_name(enemy_type: u32) -> &'static str {
+        match enemy_type {
+            ORC => "orc",
+            BAT => "bat",
+            KNIGHT => "knight",
+            WOLF => "wolf",
+            _ => "orc",
+        }
+    }
 }
 
 // Équivalent de la classe Constants.Towers en Java
@@ -102,4 +155,33 @@ pub mod projectiles {
             _ => 0.0,
         }
     }
+
+    // Homing = suit sa cible, sinon tir balistique vers la position prédite (peut rater)
+    pub fn is_homing(projectile_type: u32) -> bool {
+        match projectile_type {
+            ARROW => false,
+            BOMB => false,
+            CHAINS => true,
+            _ => true,
+        }
+    }
+
+    // Rayon de collision d'un projectile balistique (en pixels)
+    pub fn get_hit_radius(projectile_type: u32) -> f32 {
+        match projectile_type {
+            ARROW => 10.0,
+            BOMB => 14.0,
+            CHAINS => 12.0,
+            _ => 0.0,
+        }
+    }
+
+    pub fn get_sprite_name(projectile_type: u32) -> &'static str {
+        match projectile_type {
+            ARROW => "arrow",
+            CHAINS => "chains",
+            BOMB => "bomb",
+            _ => "arrow",
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,195 @@
+// src/controls.rs
+// Écran de configuration des touches (AppState::Controls)
+
+use bevy::prelude::*;
+use crate::{
+    AppState,
+    input::{InputAction, InputMap},
+    i18n::Localized,
+    menu::{NORMAL_BUTTON, HOVERED_BUTTON, PRESSED_BUTTON},
+};
+
+#[derive(Component)]
+struct ControlsUI;
+
+// Bouton qui affiche (et permet de changer) la touche d'une action
+#[derive(Component)]
+struct BindingButton(InputAction);
+
+#[derive(Component)]
+struct BindingText(InputAction);
+
+#[derive(Component)]
+enum ControlsButtonAction {
+    ResetDefaults,
+    Back,
+}
+
+// Action en attente d'une nouvelle touche
+#[derive(Resource, Default)]
+struct Rebinding(Option<InputAction>);
+
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Rebinding>()
+            .add_systems(OnEnter(AppState::Controls), setup_controls)
+            .add_systems(Update, (
+                binding_button_interaction,
+                capture_new_key,
+                controls_button_interaction,
+                update_binding_texts,
+            ).chain().run_if(in_state(AppState::Controls)))
+            .add_systems(OnExit(AppState::Controls), cleanup_controls);
+    }
+}
+
+// "KeyU" -> "U", "Digit1" -> "1"
+pub fn key_name(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name)
+        .to_string()
+}
+
+fn setup_controls(mut commands: Commands, map: Res<InputMap>, mut rebinding: ResMut<Rebinding>) {
+    rebinding.0 = None;
+    let text_style = TextStyle { font_size: 20.0, color: Color::WHITE, ..default() };
+    let button_style = Style {
+        width: Val::Px(150.0),
+        height: Val::Px(36.0),
+        margin: UiRect::all(Val::Px(4.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    // Lignes plus serrées pour que toutes les actions tiennent à l'écran
+    let binding_style = Style { height: Val::Px(28.0), margin: UiRect::all(Val::Px(2.0)), ..button_style.clone() };
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.1, 0.1, 0.1).into(),
+            ..default()
+        },
+        ControlsUI,
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section("", TextStyle { font_size: 36.0, ..text_style.clone() })
+                .with_style(Style { margin: UiRect::bottom(Val::Px(4.0)), ..default() }),
+            Localized("controls.title"),
+        ));
+        parent.spawn((
+            TextBundle::from_section("", TextStyle { font_size: 14.0, color: Color::GRAY, ..text_style.clone() })
+                .with_style(Style { margin: UiRect::bottom(Val::Px(12.0)), ..default() }),
+            Localized("controls.cancel_hint"),
+        ));
+
+        // Une ligne par action : libellé + bouton de la touche
+        for action in InputAction::ALL {
+            parent.spawn(NodeBundle {
+                style: Style { width: Val::Px(380.0), justify_content: JustifyContent::SpaceBetween, align_items: AlignItems::Center, ..default() },
+                ..default()
+            }).with_children(|row| {
+                row.spawn((TextBundle::from_section("", text_style.clone()), Localized(action.key())));
+                row.spawn((
+                    ButtonBundle { style: binding_style.clone(), background_color: NORMAL_BUTTON.into(), ..default() },
+                    BindingButton(action),
+                )).with_children(|b| {
+                    b.spawn((TextBundle::from_section(key_name(map.key(action)), text_style.clone()), BindingText(action)));
+                });
+            });
+        }
+
+        parent.spawn(NodeBundle { style: Style { margin: UiRect::top(Val::Px(20.0)), ..default() }, ..default() })
+            .with_children(|row| {
+                for (label, action) in [("controls.defaults", ControlsButtonAction::ResetDefaults), ("button.back", ControlsButtonAction::Back)] {
+                    row.spawn((
+                        ButtonBundle { style: button_style.clone(), background_color: NORMAL_BUTTON.into(), ..default() },
+                        action,
+                    )).with_children(|b| {
+                        b.spawn((TextBundle::from_section("", text_style.clone()), Localized(label)));
+                    });
+                }
+            });
+    });
+}
+
+fn binding_button_interaction(
+    mut query: Query<(&Interaction, &BindingButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    for (interaction, button, mut color) in query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                rebinding.0 = Some(button.0);
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+// Attend la prochaine touche pour l'action sélectionnée (clic droit annule : toutes les touches, Échap compris, sont assignables)
+fn capture_new_key(keys: Res<ButtonInput<KeyCode>>, mouse: Res<ButtonInput<MouseButton>>, mut rebinding: ResMut<Rebinding>, mut map: ResMut<InputMap>) {
+    let Some(action) = rebinding.0 else { return; };
+    if mouse.just_pressed(MouseButton::Right) {
+        rebinding.0 = None;
+        return;
+    }
+    let Some(&key) = keys.get_just_pressed().next() else { return; };
+    map.bind(action, key);
+    map.save();
+    rebinding.0 = None;
+}
+
+fn controls_button_interaction(
+    mut query: Query<(&Interaction, &ControlsButtonAction, &mut BackgroundColor), Changed<Interaction>>,
+    mut map: ResMut<InputMap>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, action, mut color) in query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                match action {
+                    ControlsButtonAction::ResetDefaults => {
+                        map.reset();
+                        map.save();
+                    }
+                    ControlsButtonAction::Back => next_state.set(AppState::Menu),
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+fn update_binding_texts(map: Res<InputMap>, rebinding: Res<Rebinding>, mut texts: Query<(&mut Text, &BindingText)>) {
+    if !map.is_changed() && !rebinding.is_changed() { return; }
+    for (mut text, binding) in texts.iter_mut() {
+        text.sections[0].value = if rebinding.0 == Some(binding.0) {
+            "Press a key...".to_string()
+        } else {
+            key_name(map.key(binding.0))
+        };
+    }
+}
+
+fn cleanup_controls(mut commands: Commands, query: Query<Entity, With<ControlsUI>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
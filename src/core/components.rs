@@ -1 +0,0 @@
-use bevy::prelude::*;
@@ -1,9 +0,0 @@
-use bevy::prelude::*;
-
-//Window
-pub const WINDOW_TITLE: &str = "Flappy Bird";
-pub const WINDOW_HEIGHT: f32 = 640.0;
-pub const WINDOW_WIDTH: f32 = 480.0;
-
-//Colors
-pub const BACKGROUND_COLOR: Color = Color::srgb(135.0 / 255.0, 204.0 / 255.0, 234.6 / 255.0);
@@ -1 +1,82 @@
 use bevy::prelude::*;
+use crate::tower::TowerType;
+
+// --- Événements de combat ---
+// Le pipeline : ProjectileHit -> DamageEvent -> EnemyKilled
+// Les autres systèmes (économie, stats, effets, UI) réagissent à ces événements
+
+// Un projectile a atteint sa cible
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ProjectileHit {
+    pub projectile: Entity,
+    pub target: Entity,
+    pub source: Entity, // La tour qui a tiré
+    pub source_type: TowerType, // Son type, qui reste connu si la tour est vendue entre-temps
+    pub damage: i32,
+    pub projectile_type: u32,
+    pub position: Vec2, // Point d'impact
+}
+
+// Une tour vient de tirer
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TowerFired {
+    pub tower: Entity,
+    pub tower_type: TowerType,
+    pub target: Entity,
+    pub direction: Vec2, // Direction normalisée du tir
+}
+
+// Dégâts appliqués à une entité qui a de la vie
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: i32,
+    pub source: Option<Entity>,
+    pub source_type: Option<TowerType>,
+}
+
+// Un ennemi vient de mourir (envoyé une seule fois par ennemi)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyKilled {
+    pub enemy: Entity,
+    pub enemy_type: u32,
+    pub killer: Option<Entity>, // La tour qui a porté le coup fatal
+    pub position: Vec2,
+}
+
+// Un ennemi a atteint la fin du chemin
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyLeaked {
+    pub enemy: Entity,
+    pub position: Vec2,
+}
+
+// --- Événements du joueur ---
+
+// Une tour vient d'être construite sur la grille
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TowerBuilt {
+    pub tower: Entity,
+    pub tower_type: TowerType,
+}
+
+// Achat refusé faute d'or (pose ou amélioration d'une tour)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PurchaseDenied {
+    pub cost: i32,
+}
+
+// --- Événements des vagues ---
+
+// Une vague commence (early_seconds > 0 si le joueur l'a appelée avant la fin de la pause)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WaveStarted {
+    pub wave: usize,
+    pub early_seconds: f32,
+}
+
+// Tous les ennemis de la vague sont morts ou passés
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WaveCleared {
+    pub wave: usize,
+}
@@ -1,11 +1,3 @@
-pub mod components;
-pub mod constants;
 pub mod events;
-pub mod resources;
-pub mod states;
 
-pub use components::*;
-pub use constants::*;
 pub use events::*;
-pub use resources::*;
-pub use states::*;
@@ -0,0 +1,73 @@
+// src/difficulty.rs
+// Niveau de difficulté choisi dans le menu : or et vies de départ, vie et vitesse des
+// ennemis, primes. Lu par setup_game, les vagues (apparition des ennemis) et l'économie
+
+use bevy::prelude::*;
+use serde::{Serialize, Deserialize};
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Endless,
+}
+
+impl Difficulty {
+    // Clé du libellé traduit (i18n.rs)
+    pub fn key(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "difficulty.easy",
+            Difficulty::Normal => "difficulty.normal",
+            Difficulty::Hard => "difficulty.hard",
+            Difficulty::Endless => "difficulty.endless",
+        }
+    }
+
+    pub fn next(&self) -> Difficulty {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Endless,
+            Difficulty::Endless => Difficulty::Easy,
+        }
+    }
+
+    pub fn starting_gold(&self) -> i32 {
+        match self {
+            Difficulty::Easy => 400,
+            Difficulty::Normal | Difficulty::Endless => 300,
+            Difficulty::Hard => 250,
+        }
+    }
+
+    pub fn starting_lives(&self) -> i32 {
+        match self {
+            Difficulty::Easy => 5,
+            Difficulty::Normal | Difficulty::Endless => 3,
+            Difficulty::Hard => 2,
+        }
+    }
+
+    // Multiplicateurs : (vie des ennemis, vitesse des ennemis, primes)
+    fn multipliers(&self) -> (f32, f32, f32) {
+        match self {
+            Difficulty::Easy => (0.75, 0.9, 1.25),
+            Difficulty::Normal | Difficulty::Endless => (1.0, 1.0, 1.0),
+            Difficulty::Hard => (1.35, 1.15, 0.8),
+        }
+    }
+
+    pub fn enemy_health(&self, base: u32) -> i32 {
+        ((base as f32 * self.multipliers().0).round() as i32).max(1)
+    }
+
+    pub fn enemy_speed(&self, base: f32) -> f32 {
+        base * self.multipliers().1
+    }
+
+    pub fn bounty(&self, base: i32) -> i32 {
+        ((base as f32 * self.multipliers().2).round() as i32).max(1)
+    }
+}
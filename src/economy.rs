@@ -0,0 +1,205 @@
+// src/economy.rs
+// Économie de la partie : primes par type d'ennemi, bonus de fin de vague, intérêts (optionnels) sur l'or
+// gardé pendant la pause, et un registre des recettes / dépenses affiché au survol de l'or
+
+use std::collections::BTreeMap;
+use bevy::prelude::*;
+use serde::{Serialize, Deserialize};
+use crate::{
+    AppState,
+    game::{PlayerStats, GameComponent},
+    hud::GoldCounter,
+    wave::update_waves,
+    difficulty::Difficulty,
+    core::{EnemyKilled, WaveStarted, WaveCleared},
+    constants::enemies as EnemyConstants,
+    i18n::Localization,
+};
+
+// Bonus de fin de vague : base + montant par numéro de vague
+const WAVE_BONUS_BASE: i32 = 20;
+const WAVE_BONUS_PER_WAVE: i32 = 5;
+
+// Intérêts versés au début de chaque vague (sauf la première) sur l'or non dépensé, si actifs
+const INTEREST_RATE: f32 = 0.05;
+const INTEREST_CAP: i32 = 25;
+
+// Règles de l'économie qui peuvent être désactivées
+#[derive(Resource, Debug, Clone)]
+pub struct EconomyRules {
+    pub interest: bool,
+}
+
+impl Default for EconomyRules {
+    fn default() -> Self {
+        Self { interest: true }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LedgerEntry {
+    Bounty,
+    WaveBonus,
+    Interest,
+    TowerSale,
+    TowerPurchase,
+    TowerUpgrade,
+}
+
+impl LedgerEntry {
+    const ALL: [LedgerEntry; 6] = [
+        LedgerEntry::Bounty,
+        LedgerEntry::WaveBonus,
+        LedgerEntry::Interest,
+        LedgerEntry::TowerSale,
+        LedgerEntry::TowerPurchase,
+        LedgerEntry::TowerUpgrade,
+    ];
+
+    // Clé du libellé traduit (i18n.rs)
+    fn key(&self) -> &'static str {
+        match self {
+            LedgerEntry::Bounty => "ledger.bounty",
+            LedgerEntry::WaveBonus => "ledger.wave_bonus",
+            LedgerEntry::Interest => "ledger.interest",
+            LedgerEntry::TowerSale => "ledger.tower_sale",
+            LedgerEntry::TowerPurchase => "ledger.tower_purchase",
+            LedgerEntry::TowerUpgrade => "ledger.tower_upgrade",
+        }
+    }
+
+    fn is_income(&self) -> bool {
+        matches!(self, LedgerEntry::Bounty | LedgerEntry::WaveBonus | LedgerEntry::Interest | LedgerEntry::TowerSale)
+    }
+}
+
+// Tous les mouvements d'or de la partie passent par ici (montants toujours positifs)
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    totals: BTreeMap<LedgerEntry, i32>,
+}
+
+impl Ledger {
+    pub fn earn(&mut self, stats: &mut PlayerStats, entry: LedgerEntry, amount: i32) {
+        stats.money += amount;
+        *self.totals.entry(entry).or_default() += amount;
+    }
+
+    pub fn spend(&mut self, stats: &mut PlayerStats, entry: LedgerEntry, amount: i32) {
+        stats.money -= amount;
+        *self.totals.entry(entry).or_default() += amount;
+    }
+
+    pub fn total(&self, entry: LedgerEntry) -> i32 {
+        self.totals.get(&entry).copied().unwrap_or(0)
+    }
+
+    pub fn income(&self) -> i32 {
+        LedgerEntry::ALL.iter().filter(|e| e.is_income()).map(|e| self.total(*e)).sum()
+    }
+
+    pub fn expenses(&self) -> i32 {
+        LedgerEntry::ALL.iter().filter(|e| !e.is_income()).map(|e| self.total(*e)).sum()
+    }
+}
+
+fn wave_bonus(wave: usize) -> i32 {
+    WAVE_BONUS_BASE + WAVE_BONUS_PER_WAVE * (wave as i32 + 1)
+}
+
+fn interest(money: i32) -> i32 {
+    ((money.max(0) as f32 * INTEREST_RATE) as i32).min(INTEREST_CAP)
+}
+
+// Infobulle du registre, au-dessus de la barre du bas à gauche (la droite est au panneau d'info des tours)
+#[derive(Component)]
+struct LedgerTooltip;
+#[derive(Component)]
+struct LedgerText;
+
+pub struct EconomyPlugin;
+
+impl Plugin for EconomyPlugin {
+    fn build(&self, app: &mut App) {
+        // Le Ledger est remis à zéro par setup_game (et remplacé au chargement d'une partie)
+        app
+            .init_resource::<EconomyRules>()
+            .add_systems(OnEnter(AppState::Playing), setup_ledger_tooltip)
+            // Après update_waves : le bonus de la dernière vague est versé avant le passage à GameOver (PostUpdate)
+            .add_systems(Update, (pay_bounties, pay_wave_income.after(update_waves), update_ledger_tooltip).run_if(in_state(AppState::Playing)));
+    }
+}
+
+fn setup_ledger_tooltip(mut commands: Commands) {
+    commands.spawn((NodeBundle {
+        style: Style { position_type: PositionType::Absolute, left: Val::Px(10.0), bottom: Val::Px(110.0), padding: UiRect::all(Val::Px(8.0)), border: UiRect::all(Val::Px(2.0)), display: Display::None, ..default() },
+        background_color: Color::rgba(0.0, 0.0, 0.0, 0.85).into(), border_color: BorderColor(Color::BLACK), z_index: ZIndex::Global(60), ..default() }, LedgerTooltip, GameComponent,
+    )).with_children(|parent| {
+        parent.spawn((TextBundle::from_section("", TextStyle { font_size: 14.0, color: Color::WHITE, ..default() }).with_no_wrap(), LedgerText));
+    });
+}
+
+// Prime par type d'ennemi, ajustée à la difficulté (aussi affichée par effects.rs)
+pub fn bounty(difficulty: &Difficulty, enemy_type: u32) -> i32 {
+    difficulty.bounty(EnemyConstants::get_bounty(enemy_type))
+}
+
+fn pay_bounties(mut events: EventReader<EnemyKilled>, difficulty: Res<Difficulty>, mut stats: ResMut<PlayerStats>, mut ledger: ResMut<Ledger>) {
+    for event in events.read() {
+        ledger.earn(&mut stats, LedgerEntry::Bounty, bounty(&difficulty, event.enemy_type));
+    }
+}
+
+// Bonus à la fin de chaque vague, intérêts sur l'or gardé pendant la pause
+fn pay_wave_income(
+    mut started: EventReader<WaveStarted>,
+    mut cleared: EventReader<WaveCleared>,
+    rules: Res<EconomyRules>,
+    mut stats: ResMut<PlayerStats>,
+    mut ledger: ResMut<Ledger>,
+) {
+    for event in cleared.read() {
+        ledger.earn(&mut stats, LedgerEntry::WaveBonus, wave_bonus(event.wave));
+    }
+    for event in started.read() {
+        if event.wave == 0 || !rules.interest { continue; }
+        let amount = interest(stats.money);
+        ledger.earn(&mut stats, LedgerEntry::Interest, amount);
+    }
+}
+
+fn update_ledger_tooltip(
+    stats: Res<PlayerStats>,
+    rules: Res<EconomyRules>,
+    ledger: Res<Ledger>,
+    loc: Res<Localization>,
+    hover: Query<&Interaction, With<GoldCounter>>,
+    mut tooltip: Query<&mut Style, With<LedgerTooltip>>,
+    mut text: Query<&mut Text, With<LedgerText>>,
+) {
+    let shown = hover.iter().any(|i| *i != Interaction::None);
+    for mut style in tooltip.iter_mut() {
+        let display = if shown { Display::Flex } else { Display::None };
+        if style.display != display { style.display = display; }
+    }
+    if !shown { return; }
+
+    let mut value = format!("{}\n", loc.get("ledger.income"));
+    for entry in LedgerEntry::ALL.iter().filter(|e| e.is_income()) {
+        value += &format!("  {}: +{}\n", loc.get(entry.key()), ledger.total(*entry));
+    }
+    value += &format!("{}\n", loc.get("ledger.expenses"));
+    for entry in LedgerEntry::ALL.iter().filter(|e| !e.is_income()) {
+        value += &format!("  {}: -{}\n", loc.get(entry.key()), ledger.total(*entry));
+    }
+    value += &loc.format("ledger.net", &[&format!("{:+}", ledger.income() - ledger.expenses())]);
+    value += "\n";
+    value += &if rules.interest {
+        loc.format("ledger.next_interest", &[&interest(stats.money), &format!("{:.0}", INTEREST_RATE * 100.0), &INTEREST_CAP])
+    } else {
+        loc.get("ledger.interest_off").to_string()
+    };
+    for mut t in text.iter_mut() {
+        if t.sections[0].value != value { t.sections[0].value = value.clone(); }
+    }
+}
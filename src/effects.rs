@@ -0,0 +1,155 @@
+// src/effects.rs
+// Effets visuels légers déclenchés par les événements de jeu :
+// - ennemi tué : "+N" doré qui monte depuis sa position et petite gerbe de particules
+// - ennemi passé : flash rouge sur les bords de l'écran et secousse du compteur de vies
+// Tous les effets sont supprimés en quittant la partie
+
+use bevy::prelude::*;
+use crate::{
+    AppState,
+    difficulty::Difficulty,
+    economy,
+    core::{EnemyKilled, EnemyLeaked},
+};
+
+const FLOATING_TEXT_TIME: f32 = 1.0;
+const FLOATING_TEXT_SPEED: f32 = 30.0; // px/s vers le haut
+const PARTICLE_COUNT: usize = 8;
+const PARTICLE_TIME: f32 = 0.5;
+const EDGE_FLASH_TIME: f32 = 0.4;
+const SHAKE_TIME: f32 = 0.4;
+const SHAKE_AMPLITUDE: f32 = 4.0; // px
+
+// Marque toutes les entités d'effet, pour le nettoyage
+#[derive(Component)]
+struct Effect;
+
+// Texte ou particule qui se déplace et s'efface avant de disparaître
+#[derive(Component)]
+struct Fading {
+    timer: Timer,
+    velocity: Vec2,
+}
+
+#[derive(Component)]
+struct EdgeFlash {
+    timer: Timer,
+}
+
+// À ajouter au texte qui affiche les vies : il tremble quand un ennemi passe
+#[derive(Component)]
+pub struct ShakeOnLeak {
+    timer: Timer,
+}
+
+impl Default for ShakeOnLeak {
+    fn default() -> Self {
+        // Timer déjà terminé : pas de secousse au départ
+        let mut timer = Timer::from_seconds(SHAKE_TIME, TimerMode::Once);
+        timer.tick(timer.duration());
+        Self { timer }
+    }
+}
+
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (spawn_kill_effects, spawn_leak_effects, update_fading, update_edge_flash, update_shake).run_if(in_state(AppState::Playing)))
+            .add_systems(OnExit(AppState::Playing), cleanup_effects);
+    }
+}
+
+fn spawn_kill_effects(mut commands: Commands, mut events: EventReader<EnemyKilled>, difficulty: Res<Difficulty>) {
+    for event in events.read() {
+        commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(format!("+{}", economy::bounty(&difficulty, event.enemy_type)), TextStyle { font_size: 14.0, color: Color::GOLD, ..default() }),
+                transform: Transform::from_translation(event.position.extend(5.0) + Vec3::new(0.0, 12.0, 0.0)),
+                ..default()
+            },
+            Fading { timer: Timer::from_seconds(FLOATING_TEXT_TIME, TimerMode::Once), velocity: Vec2::new(0.0, FLOATING_TEXT_SPEED) },
+            Effect,
+        ));
+        for i in 0..PARTICLE_COUNT {
+            // Répartis en cercle, avec un peu de hasard sur l'angle et la vitesse
+            let angle = (i as f32 + fastrand::f32()) * std::f32::consts::TAU / PARTICLE_COUNT as f32;
+            let speed = 40.0 + fastrand::f32() * 50.0;
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite { color: Color::rgb(0.75, 0.15, 0.1), custom_size: Some(Vec2::splat(3.0)), ..default() },
+                    transform: Transform::from_translation(event.position.extend(4.0)),
+                    ..default()
+                },
+                Fading { timer: Timer::from_seconds(PARTICLE_TIME, TimerMode::Once), velocity: Vec2::from_angle(angle) * speed },
+                Effect,
+            ));
+        }
+    }
+}
+
+fn spawn_leak_effects(mut commands: Commands, mut events: EventReader<EnemyLeaked>, mut shakes: Query<&mut ShakeOnLeak>) {
+    if events.is_empty() { return; }
+    events.clear();
+
+    commands.spawn((
+        NodeBundle {
+            style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), position_type: PositionType::Absolute, border: UiRect::all(Val::Px(12.0)), ..default() },
+            border_color: BorderColor(Color::rgba(0.9, 0.1, 0.1, 0.6)),
+            focus_policy: bevy::ui::FocusPolicy::Pass,
+            z_index: ZIndex::Global(90),
+            ..default()
+        },
+        EdgeFlash { timer: Timer::from_seconds(EDGE_FLASH_TIME, TimerMode::Once) },
+        Effect,
+    ));
+    for mut shake in shakes.iter_mut() {
+        shake.timer.reset();
+    }
+}
+
+fn update_fading(mut commands: Commands, time: Res<Time>, mut query: Query<(Entity, &mut Fading, &mut Transform, Option<&mut Text>, Option<&mut Sprite>)>) {
+    for (entity, mut fading, mut transform, text, sprite) in query.iter_mut() {
+        fading.timer.tick(time.delta());
+        if fading.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        transform.translation += (fading.velocity * time.delta_seconds()).extend(0.0);
+        let alpha = 1.0 - fading.timer.fraction();
+        if let Some(mut text) = text { text.sections[0].style.color.set_a(alpha); }
+        if let Some(mut sprite) = sprite { sprite.color.set_a(alpha); }
+    }
+}
+
+fn update_edge_flash(mut commands: Commands, time: Res<Time>, mut query: Query<(Entity, &mut EdgeFlash, &mut BorderColor)>) {
+    for (entity, mut flash, mut border) in query.iter_mut() {
+        flash.timer.tick(time.delta());
+        if flash.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        } else {
+            border.0.set_a(0.6 * (1.0 - flash.timer.fraction()));
+        }
+    }
+}
+
+// Oscillation horizontale qui s'amortit
+fn update_shake(time: Res<Time>, mut query: Query<(&mut ShakeOnLeak, &mut Style)>) {
+    for (mut shake, mut style) in query.iter_mut() {
+        if shake.timer.finished() { continue; }
+        shake.timer.tick(time.delta());
+        let offset = if shake.timer.finished() {
+            0.0
+        } else {
+            (shake.timer.elapsed_secs() * 60.0).sin() * SHAKE_AMPLITUDE * (1.0 - shake.timer.fraction())
+        };
+        style.left = Val::Px(offset);
+    }
+}
+
+fn cleanup_effects(mut commands: Commands, query: Query<Entity, With<Effect>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
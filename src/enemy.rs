@@ -1,36 +1,67 @@
 // src/enemy.rs
 
-use bevy::prelude::*;
+use std::sync::Mutex;
+use bevy::{prelude::*, ecs::query::BatchingStrategy};
 use crate::{
-    AppState, GameAssets, GlobalPause, 
-    game::{Path, PlayerStats},         
-    constants::enemies as EnemyConstants
+    AppState,
+    atlas::SpriteAtlas,
+    speed::gameplay_running,
+    game::Path,
+    core::{DamageEvent, EnemyKilled, EnemyLeaked},
+    projectile::apply_projectile_hits,
+    animation::{SpriteAnimator, clips},
+    tower::{TowerStats, SoldTowerStats},
+    settings::Settings,
+    constants::enemies as EnemyConstants,
 };
 
+// Vitesse de base d'un type d'ennemi en px/s (vitesse Java en pixels par tick -> ~pixels par seconde)
+pub fn base_speed(enemy_type: u32) -> f32 {
+    EnemyConstants::get_speed(enemy_type) * 100.0
+}
+
 // --- Les Composants ---
 #[derive(Component)]
 pub struct Enemy {
+    pub enemy_type: u32,
     pub speed: f32,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy)]
 pub struct Health {
     pub current: i32,
     pub max: i32,
 }
 
+// Dégâts des projectiles déjà en vol vers cet ennemi
+// Les tours ignorent un ennemi que ces projectiles vont déjà tuer
+#[derive(Component, Default)]
+pub struct PendingDamage(pub i32);
+
+impl PendingDamage {
+    pub fn is_doomed(&self, health: &Health) -> bool {
+        self.0 >= health.current
+    }
+}
+
+// Vitesse réelle de l'ennemi (px/s), utilisée par les tours pour anticiper le tir
+#[derive(Component, Default)]
+pub struct Velocity(pub Vec2);
+
 #[derive(Component)]
 pub struct HealthBar;
 
+// Fond noir derrière la barre de vie (affiché en même temps qu'elle)
+#[derive(Component)]
+pub struct HealthBarBackground;
+
 #[derive(Component)]
 pub struct PathFollower {
     pub path_index: usize,
 }
 
-#[derive(Resource)]
-struct EnemySpawnTimer {
-    timer: Timer,
-}
+// Taille minimale d'un lot pour les itérations parallèles sur les ennemis
+pub const ENEMY_BATCH_SIZE: usize = 256;
 
 // --- Les Plugin ---
 pub struct EnemyPlugin;
@@ -38,106 +69,124 @@ pub struct EnemyPlugin;
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app
-            .insert_resource(EnemySpawnTimer {
-                timer: Timer::from_seconds(1.5, TimerMode::Repeating),
-            })
+            // L'apparition des ennemis est gérée par les vagues (wave.rs)
             .add_systems(Update, 
                 (
-                    spawn_enemies, 
-                    move_enemies, 
+                    (move_enemies, despawn_leaked_enemies).chain(), 
                     animate_enemy_rotation, 
-                    enemy_death_system,
                     update_health_bars
                 )
                 // Bien faire attention à ne pas faire tourner ces systèmes quand le jeu est en pause
-                .run_if(in_state(AppState::Playing).and_then(not_paused))
+                .run_if(in_state(AppState::Playing).and_then(gameplay_running))
+            )
+            // Dégâts et morts : communs au jeu et à la simulation
+            .add_systems(Update,
+                (apply_damage, despawn_killed_enemies)
+                    .chain()
+                    .after(apply_projectile_hits)
+                    .run_if(gameplay_running)
             );
     }
 }
 
-fn not_paused(pause: Res<GlobalPause>) -> bool { !pause.0 }
-
 // ---  LesSystèmes ---
 
-fn spawn_enemies(
-    mut commands: Commands,
-    assets: Res<GameAssets>,
-    path: Res<Path>,
-    time: Res<Time>,
-    mut spawn_timer: ResMut<EnemySpawnTimer>,
-) {
-    spawn_timer.timer.tick(time.delta());
-
-    if spawn_timer.timer.just_finished() {
-        if path.points.is_empty() { return; }
-        
-        let start_pos = path.points[0]; 
-        let hp = 85; 
-        let speed = 50.0; 
-
-        commands.spawn((
-            SpriteSheetBundle {
-                texture: assets.sprite_atlas.clone(),
-                atlas: TextureAtlas { layout: assets.sprite_atlas_layout.clone(), index: 10 },
-                transform: Transform::from_xyz(start_pos.x, start_pos.y, 1.0), 
-                ..default()
-            },
-            Enemy { speed },
-            Health { current: hp, max: hp },
-            PathFollower { path_index: 1 }, 
-            Name::new("Orc"),
-        ))
-        .with_children(|parent| {
-            // Fond noir
-            parent.spawn(SpriteBundle {
+// Crée un ennemi du type donné à une position du chemin (vagues et chargement de partie)
+// La vie et la vitesse sont déjà ajustées à la difficulté par l'appelant
+pub fn spawn_enemy(commands: &mut Commands, atlas: &SpriteAtlas, enemy_type: u32, position: Vec2, path_index: usize, health: Health, speed: f32) -> Entity {
+
+    commands.spawn((
+        SpriteSheetBundle {
+            texture: atlas.image.clone(),
+            atlas: atlas.sprite(EnemyConstants::get_sprite_name(enemy_type)),
+            transform: Transform::from_xyz(position.x, position.y, 1.0), 
+            ..default()
+        },
+        Enemy { enemy_type, speed },
+        health,
+        PendingDamage::default(),
+        Velocity::default(),
+        SpriteAnimator::playing(atlas.clip(clips::walk(enemy_type))),
+        PathFollower { path_index }, 
+        Name::new(EnemyConstants::get_name(enemy_type)),
+    ))
+    .with_children(|parent| {
+        // Fond noir
+        parent.spawn((
+            SpriteBundle {
                 sprite: Sprite { color: Color::BLACK, custom_size: Some(Vec2::new(22.0, 6.0)), ..default() },
                 transform: Transform::from_xyz(0.0, 20.0, 0.1), 
                 ..default()
-            });
-            // Barre rouge
-            parent.spawn((
-                SpriteBundle {
-                    sprite: Sprite { color: Color::RED, custom_size: Some(Vec2::new(20.0, 4.0)), ..default() },
-                    transform: Transform::from_xyz(0.0, 20.0, 0.2), 
-                    ..default()
-                },
-                HealthBar,
-            ));
-        });
-    }
+            },
+            HealthBarBackground,
+        ));
+        // Barre rouge
+        parent.spawn((
+            SpriteBundle {
+                sprite: Sprite { color: Color::RED, custom_size: Some(Vec2::new(20.0, 4.0)), ..default() },
+                transform: Transform::from_xyz(0.0, 20.0, 0.2), 
+                ..default()
+            },
+            HealthBar,
+        ));
+    })
+    .id()
 }
 
 fn move_enemies(
-    mut commands: Commands,
-    mut query: Query<(Entity, &mut Transform, &Enemy, &mut PathFollower)>,
+    mut query: Query<(Entity, &mut Transform, &Enemy, &mut PathFollower, &mut Velocity)>,
     path: Res<Path>,
     time: Res<Time>,
-    mut stats: ResMut<PlayerStats>,
+    mut leaks: EventWriter<EnemyLeaked>,
 ) {
     if path.points.is_empty() { return; }
 
-    for (entity, mut transform, enemy, mut follower) in query.iter_mut() {
-        if follower.path_index >= path.points.len() {
-            // Arrivé au bout -> Dégâts au joueur
-            stats.lives -= 1;
-            commands.entity(entity).despawn_recursive();
-            continue;
-        }
+    let dt = time.delta_seconds();
+    // Les arrivées sont rares : un simple Mutex suffit pour les collecter depuis les threads
+    let arrived = Mutex::new(Vec::new());
 
-        let target = path.points[follower.path_index];
-        let dir = target - transform.translation.truncate();
-        let dist = dir.length();
-        let step = enemy.speed * time.delta_seconds();
-
-        if dist <= step {
-            transform.translation.x = target.x;
-            transform.translation.y = target.y;
-            follower.path_index += 1;
-        } else {
-            let movement = dir.normalize() * step;
-            transform.translation.x += movement.x;
-            transform.translation.y += movement.y;
-        }
+    query
+        .par_iter_mut()
+        .batching_strategy(BatchingStrategy::new().min_batch_size(ENEMY_BATCH_SIZE))
+        .for_each(|(entity, mut transform, enemy, mut follower, mut velocity)| {
+            if follower.path_index >= path.points.len() {
+                // Arrivé au bout -> traité après coup via EnemyLeaked
+                arrived.lock().unwrap().push((entity, transform.translation.truncate()));
+                return;
+            }
+            let moved = step_along_path(&mut transform.translation, &mut follower.path_index, &path.points, enemy.speed * dt);
+            if dt > 0.0 { velocity.0 = moved / dt; }
+        });
+
+    for (enemy, position) in arrived.into_inner().unwrap() {
+        leaks.send(EnemyLeaked { enemy, position });
+    }
+}
+
+// Avance une position vers le prochain point du chemin (partagé avec la simulation)
+// Retourne le déplacement effectué
+pub fn step_along_path(translation: &mut Vec3, path_index: &mut usize, points: &[Vec2], step: f32) -> Vec2 {
+    let target = points[*path_index];
+    let dir = target - translation.truncate();
+    let dist = dir.length();
+
+    if dist <= step {
+        translation.x = target.x;
+        translation.y = target.y;
+        *path_index += 1;
+        dir
+    } else {
+        let movement = dir.normalize() * step;
+        translation.x += movement.x;
+        translation.y += movement.y;
+        movement
+    }
+}
+
+// Les vies sont retirées par le jeu (game.rs), ici on ne fait que retirer l'ennemi
+fn despawn_leaked_enemies(mut commands: Commands, mut events: EventReader<EnemyLeaked>) {
+    for event in events.read() {
+        commands.entity(event.enemy).despawn_recursive();
     }
 }
 
@@ -154,33 +203,92 @@ fn animate_enemy_rotation(mut query: Query<(&mut Transform, &PathFollower)>, pat
     }
 }
 
+// Visibilité selon l'option du joueur (toujours, si blessé, jamais)
 fn update_health_bars(
-    mut bar_query: Query<(&mut Transform, &Parent, &mut Visibility), With<HealthBar>>,
+    settings: Res<Settings>,
+    mut bar_query: Query<(&mut Transform, &Parent, &mut Visibility, Has<HealthBar>), Or<(With<HealthBar>, With<HealthBarBackground>)>>,
     health_query: Query<&Health>,
 ) {
-    for (mut transform, parent, mut vis) in bar_query.iter_mut() {
+    for (mut transform, parent, mut vis, is_bar) in bar_query.iter_mut() {
         if let Ok(health) = health_query.get(parent.get()) {
-            // Masquer si plein
-            if health.current >= health.max {
-                *vis = Visibility::Hidden;
-            } else {
+            if settings.health_bars.shows(health.current, health.max) {
                 *vis = Visibility::Inherited;
-                let percent = (health.current as f32 / health.max as f32).clamp(0.0, 1.0);
-                transform.scale.x = percent;
+                if is_bar { transform.scale.x = (health.current as f32 / health.max as f32).clamp(0.0, 1.0); }
+            } else {
+                *vis = Visibility::Hidden;
             }
         }
     }
 }
 
-fn enemy_death_system(
-    mut commands: Commands, 
-    query: Query<(Entity, &Health)>,
-    mut stats: ResMut<PlayerStats>,
+// Applique les dégâts et envoie EnemyKilled quand la vie tombe à 0
+// Les dégâts réellement infligés et le kill sont crédités à la tour source
+pub fn apply_damage(
+    mut damages: EventReader<DamageEvent>,
+    mut query: Query<(&mut Health, &Transform, &Enemy)>,
+    mut tower_stats: Query<&mut TowerStats>,
+    mut sold_towers: Option<ResMut<SoldTowerStats>>,
+    mut killed: EventWriter<EnemyKilled>,
 ) {
-    for (entity, health) in query.iter() {
-        if health.current <= 0 {
-            stats.money += 5; 
-            commands.entity(entity).despawn_recursive();
+    for event in damages.read() {
+        let Ok((mut health, transform, enemy)) = query.get_mut(event.target) else { continue; };
+        // Déjà mort plus tôt dans la frame : pas de deuxième kill
+        if health.current <= 0 { continue; }
+
+        let dealt = event.amount.min(health.current);
+        health.current -= event.amount;
+        let kill = health.current <= 0;
+        match event.source.and_then(|source| tower_stats.get_mut(source).ok()) {
+            Some(mut stats) => {
+                stats.damage += dealt as i64;
+                if kill { stats.kills += 1; }
+            }
+            // Tour vendue pendant le vol du projectile : on crédite le bilan des tours vendues de ce type
+            None => {
+                if let (Some(tower_type), Some(sold)) = (event.source_type, sold_towers.as_mut()) {
+                    sold.credit(tower_type, TowerStats { kills: kill as u32, damage: dealt as i64, shots: 0 });
+                }
+            }
+        }
+        if kill {
+            killed.send(EnemyKilled {
+                enemy: event.target,
+                enemy_type: enemy.enemy_type,
+                killer: event.source,
+                position: transform.translation.truncate(),
+            });
         }
     }
-}
\ No newline at end of file
+}
+
+fn despawn_killed_enemies(mut commands: Commands, mut events: EventReader<EnemyKilled>) {
+    for event in events.read() {
+        commands.entity(event.enemy).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINTS: [Vec2; 3] = [Vec2::new(0.0, 0.0), Vec2::new(100.0, 0.0), Vec2::new(100.0, 100.0)];
+
+    #[test]
+    fn step_moves_towards_the_next_point() {
+        let (mut translation, mut path_index) = (Vec3::new(0.0, 0.0, 1.0), 1);
+        let moved = step_along_path(&mut translation, &mut path_index, &POINTS, 30.0);
+        assert_eq!(moved, Vec2::new(30.0, 0.0));
+        assert_eq!(translation, Vec3::new(30.0, 0.0, 1.0));
+        assert_eq!(path_index, 1);
+    }
+
+    #[test]
+    fn overshooting_step_stops_on_the_waypoint() {
+        let (mut translation, mut path_index) = (Vec3::new(90.0, 0.0, 1.0), 1);
+        let moved = step_along_path(&mut translation, &mut path_index, &POINTS, 25.0);
+        // Le reste du pas est perdu : l'ennemi s'arrête sur le point et vise le suivant
+        assert_eq!(moved, Vec2::new(10.0, 0.0));
+        assert_eq!(translation, Vec3::new(100.0, 0.0, 1.0));
+        assert_eq!(path_index, 2);
+    }
+}
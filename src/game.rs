@@ -1,13 +1,24 @@
 use bevy::{prelude::*, sprite::SpriteSheetBundle, window::PrimaryWindow, ui::node_bundles::AtlasImageBundle,};
 use crate::{
     AppState, 
-    GameAssets,
-    GlobalPause, 
     level,
-    constants::tiles as TileTypes,
-    tower::{Tower, TowerType},
-    enemy::Enemy,
-    projectile::Projectile,
+    atlas::SpriteAtlas,
+    camera::MainCamera,
+    i18n::{Localization, Localized},
+    settings::Settings,
+    tower::{Tower, TowerType, TowerStats, SoldTowerStats, MAX_TIER},
+    input::{ActionState, InputAction},
+    enemy::{Enemy, Health, PendingDamage, Velocity},
+    projectile::{Projectile, predict_intercept},
+    constants::projectiles as ProjectileConstants,
+    animation::SpriteAnimator,
+    core::{EnemyLeaked, PurchaseDenied, TowerBuilt, TowerFired},
+    speed::{GameSpeed, SpeedSetting, gameplay_running, spawn_speed_buttons},
+    wave::{WaveManager, ReplaySeed, spawn_call_wave_button},
+    score::Score,
+    economy::{Ledger, LedgerEntry},
+    difficulty::Difficulty,
+    hud::spawn_hud_counters,
 };
 
 
@@ -37,16 +48,14 @@ pub struct Path {
     pub points: Vec<Vec2>, // Liste des points du chemin
 }
 
-// Ressource pour la tour sélectionnée dans le menu
+// Ressource pour la tour sélectionnée dans le menu (à construire)
+// ou pour une tour déjà posée (à améliorer / vendre)
 #[derive(Resource, Default)]
 struct SelectedTower {
     tower_type: Option<TowerType>,
+    placed: Option<Entity>,
 }
 
-// État local du jeu (si le jeu est en Pause ou pas)
-#[derive(Resource, Default)]
-struct GameState { pub is_paused: bool }
-
 #[derive(Resource)]
 pub struct PlayerStats {
     pub money: i32,
@@ -59,11 +68,19 @@ struct TowerButton {
     tower_type: TowerType,
 }
 
+// Infobulle affichée au survol d'un bouton de tour
 #[derive(Component)]
-struct MoneyText;
+struct TowerTooltip;
 
+// Icône du bouton (grisée si la tour est trop chère)
 #[derive(Component)]
-struct LivesText;
+struct TowerButtonIcon;
+
+// Panneau latéral avec le détail de la tour survolée ou sélectionnée
+#[derive(Component)]
+struct TowerInfoPanel;
+#[derive(Component)]
+struct TowerInfoText;
 
 
 // Composants UI
@@ -83,38 +100,82 @@ impl Plugin for GamePlugin {
         app
             // On initialise au démarrage, mais setup_game le fera aussi
             .init_resource::<SelectedTower>()
-            .insert_resource(PlayerStats { money: 300, lives: 3 })
             .add_systems(OnEnter(AppState::Playing), (setup_game, setup_game_ui))
             .add_systems(Update, (
                 tower_button_interaction, 
                 grid_click_interaction, 
                 ui_button_interaction,
                 update_ui_text,
+                update_tower_tooltips,
+                update_tower_info_panel,
+                update_tower_affordability,
+                keyboard_actions,
+                highlight_selected_tower,
             ).run_if(in_state(AppState::Playing)))
-            .add_systems(Update, tower_shooting.run_if(in_state(AppState::Playing).and_then(not_paused))) 
+            .add_systems(Update, tower_shooting.run_if(in_state(AppState::Playing).and_then(gameplay_running))) 
+            .add_systems(Update, count_shots.run_if(in_state(AppState::Playing)))
+            // Vies : réagissent aux événements de combat (l'or est géré par economy.rs)
+            .add_systems(Update, apply_leak_damage.run_if(in_state(AppState::Playing)))
             .add_systems(OnExit(AppState::Playing), cleanup_game);
     }
 }
 
-fn not_paused(pause: Res<GlobalPause>) -> bool { !pause.0 }
-
+// Sprites d'une tuile du niveau : fond, calque éventuel par-dessus, et rotation (du calque s'il existe)
+pub struct TileSprites {
+    pub base: &'static str,
+    pub overlay: Option<&'static str>,
+    pub rotation: Quat,
+}
 
-pub fn get_atlas_index(x: usize, y: usize) -> usize {
-    y * 10 + x
+pub fn tile_sprites(tile_id: u32) -> TileSprites {
+    // Les variantes d'une même tuile se suivent par quart de tour
+    let quarter = |id: u32| match id % 4 {
+        1 => Quat::from_rotation_z(-90.0f32.to_radians()),
+        2 => Quat::from_rotation_z(180.0f32.to_radians()),
+        3 => Quat::from_rotation_z(90.0f32.to_radians()),
+        _ => Quat::IDENTITY,
+    };
+    let simple = |base, rotation| TileSprites { base, overlay: None, rotation };
+    let composite = |base, overlay, rotation| TileSprites { base, overlay: Some(overlay), rotation };
+    match tile_id {
+        0 => simple("grass", Quat::IDENTITY),
+        2 | 3 => simple("road", quarter(tile_id - 2)), // Droite : horizontale (2) ou verticale (3)
+        4..=7 => simple("road_corner", quarter(tile_id)),
+        // Eau + Sable/Terre
+        8..=11 => composite("water", "shore_corner", quarter(tile_id)),
+        12..=15 => composite("water", "shore_edge", quarter(tile_id)),
+        16..=19 => composite("water", "shore_inner", quarter(tile_id)),
+        20 => composite("road", "start", Quat::IDENTITY),
+        21 => composite("road", "end", Quat::IDENTITY),
+        _ => simple("water", Quat::IDENTITY),
+    }
 }
 
 // équivalent constructeur Playing()
-fn setup_game(
+pub fn setup_game(
     mut commands: Commands, 
-    assets: Res<GameAssets>, 
-    mut pause: ResMut<GlobalPause>,
-    sim_entities: Query<Entity, With<crate::simulation::SimComponent>>,
+    atlas: Res<SpriteAtlas>, 
+    difficulty: Res<Difficulty>,
+    replay: Option<Res<ReplaySeed>>,
+    settings: Res<Settings>,
+    mut speed: ResMut<GameSpeed>,
 ) {
     println!("Lancement du jeu (Playing) !");
 
-    pause.0 = false;
+    // Vitesse de départ choisie dans les options
+    speed.reset(settings.default_speed);
 
-    commands.insert_resource(PlayerStats { money: 300, lives: 3 });
+    // Or et vies de départ selon la difficulté choisie dans le menu
+    commands.insert_resource(PlayerStats { money: difficulty.starting_gold(), lives: difficulty.starting_lives() });
+    // Endless : vagues générées à partir d'une graine, tirée au hasard sauf si on rejoue une partie
+    commands.insert_resource(match *difficulty {
+        Difficulty::Endless => WaveManager::endless(replay.map(|r| r.0).unwrap_or_else(|| fastrand::u64(..))),
+        _ => WaveManager::default(),
+    });
+    commands.remove_resource::<ReplaySeed>();
+    commands.insert_resource(Score::default());
+    commands.insert_resource(Ledger::default());
+    commands.insert_resource(SoldTowerStats::default());
     commands.init_resource::<SelectedTower>();
 
     let level_data = level::get_level_data();
@@ -122,93 +183,34 @@ fn setup_game(
     // Positions temporaires pour le pathfinding
     let mut start_pos = Vec2::ZERO;
     
-    // Configuration de la grille
-    const TILE_SIZE: f32 = 32.0;
-    const MAP_WIDTH: f32 = 20.0 * TILE_SIZE;
-    const MAP_HEIGHT: f32 = 20.0 * TILE_SIZE;
-    
-    // Prise en compte de l'UI en bas pour le décalage vertical
-    let vertical_shift = 50.0;
-
-    let x_offset = -MAP_WIDTH / 2.0 + TILE_SIZE / 2.0;
-    // ajout du vertical_shift ici
-    let y_offset = (MAP_HEIGHT / 2.0 - TILE_SIZE / 2.0) + vertical_shift;
-
     for (y, row) in level_data.iter().enumerate() {
         for (x, &tile_id) in row.iter().enumerate() {
             
             // Position dans le monde
-            let pos = Vec2::new(
-                x_offset + x as f32 * TILE_SIZE,
-                y_offset - y as f32 * TILE_SIZE,
-            );
+            let pos = level::tile_to_world(x, y);
             
             let tile_type = get_tile_type(tile_id);
-            
-            let mut rotation = Quat::IDENTITY; 
-            let mut index = 0; 
-
-            // Logique d'affichage
-            match tile_id {
-                // --- Tuiles Composites (Eau + Sable/Terre) ---
-                8 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(5, 0), Quat::IDENTITY),
-                9 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(5, 0), Quat::from_rotation_z(-90.0f32.to_radians())),
-                10 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(5, 0), Quat::from_rotation_z(180.0f32.to_radians())),
-                11 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(5, 0), Quat::from_rotation_z(90.0f32.to_radians())),
-                
-                12 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(6, 0), Quat::IDENTITY),
-                13 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(6, 0), Quat::from_rotation_z(-90.0f32.to_radians())),
-                14 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(6, 0), Quat::from_rotation_z(180.0f32.to_radians())),
-                15 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(6, 0), Quat::from_rotation_z(90.0f32.to_radians())),
-
-                16 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(4, 0), Quat::IDENTITY),
-                17 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(4, 0), Quat::from_rotation_z(-90.0f32.to_radians())),
-                18 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(4, 0), Quat::from_rotation_z(180.0f32.to_radians())),
-                19 => spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(0, 0), get_atlas_index(4, 0), Quat::from_rotation_z(90.0f32.to_radians())),
-
-                // --- Tuiles Simples ---
-                _ => {
-                    (index, rotation) = match tile_id {
-                        0 => (get_atlas_index(9, 0), Quat::IDENTITY),
-                        1 => (get_atlas_index(0, 0), Quat::IDENTITY),
-                        2 => (get_atlas_index(8, 0), Quat::IDENTITY),
-                        3 => (get_atlas_index(8, 0), Quat::from_rotation_z(-90.0f32.to_radians())),
-                        4 => (get_atlas_index(7, 0), Quat::IDENTITY),
-                        5 => (get_atlas_index(7, 0), Quat::from_rotation_z(-90.0f32.to_radians())),
-                        6 => (get_atlas_index(7, 0), Quat::from_rotation_z(180.0f32.to_radians())),
-                        7 => (get_atlas_index(7, 0), Quat::from_rotation_z(90.0f32.to_radians())),
-
-                        20 => { // START
-                            spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(8, 0), get_atlas_index(7, 2), Quat::IDENTITY);
-                            (999, Quat::IDENTITY) // 999 = Ne pas spawner de tuile simple supplémentaire
-                        },
-                        21 => { // END
-                            spawn_composite_tile(&mut commands, &assets, pos, get_atlas_index(8, 0), get_atlas_index(8, 2), Quat::IDENTITY);
-                            (999, Quat::IDENTITY)
-                        },
-                        _ => (get_atlas_index(0, 0), Quat::IDENTITY),
-                    };
-
-                    if index != 999 {
-                        commands.spawn((
-                            SpriteSheetBundle {
-                                texture: assets.sprite_atlas.clone(),
-                                atlas: TextureAtlas {
-                                    layout: assets.sprite_atlas_layout.clone(),
-                                    index,
-                                },
-                                transform: Transform {
-                                    translation: pos.extend(0.0),
-                                    rotation,
-                                    ..default()
-                                },
+
+            // Logique d'affichage : tuile simple, ou composite (fond + calque tourné par-dessus)
+            let sprites = tile_sprites(tile_id);
+            match sprites.overlay {
+                Some(overlay) => spawn_composite_tile(&mut commands, &atlas, pos, sprites.base, overlay, sprites.rotation),
+                None => {
+                    commands.spawn((
+                        SpriteSheetBundle {
+                            texture: atlas.image.clone(),
+                            atlas: atlas.sprite(sprites.base),
+                            transform: Transform {
+                                translation: pos.extend(0.0),
+                                rotation: sprites.rotation,
                                 ..default()
                             },
-                            GameTile { tile_type },
-                            GameComponent,
-                            Name::new(format!("Tile ({x},{y})")),
-                        ));
-                    }
+                            ..default()
+                        },
+                        GameTile { tile_type },
+                        GameComponent,
+                        Name::new(format!("Tile ({x},{y})")),
+                    ));
                 }
             }
 
@@ -225,8 +227,8 @@ fn setup_game(
     path_points.push(start_pos);
 
     // convertion de la position de départ (pixels) en coordonnées de grille (0-19)
-    let mut grid_x = ((start_pos.x - x_offset) / TILE_SIZE).round() as i32;
-    let mut grid_y = ((y_offset - start_pos.y) / TILE_SIZE).round() as i32;
+    let (grid_x, grid_y) = level::world_to_tile(start_pos).expect("départ hors de la carte");
+    let (mut grid_x, mut grid_y) = (grid_x as i32, grid_y as i32);
     
     let mut last_grid_pos = (grid_x, grid_y); // Pour ne pas revenir en arrière
 
@@ -252,10 +254,7 @@ fn setup_game(
                 grid_x = nx;
                 grid_y = ny;
 
-                let next_world_pos = Vec2::new(
-                    x_offset + nx as f32 * TILE_SIZE,
-                    y_offset - ny as f32 * TILE_SIZE,
-                );
+                let next_world_pos = level::tile_to_world(nx as usize, ny as usize);
                 path_points.push(next_world_pos);
                 found_next = true;
                 
@@ -283,28 +282,47 @@ fn setup_game(
     });
 }
 
-fn spawn_ui_button<T: Component>(parent: &mut ChildBuilder, text: &str, marker: T) {
+fn spawn_ui_button<T: Component>(parent: &mut ChildBuilder, key: &'static str, marker: T) {
     parent.spawn((
         ButtonBundle {
             style: Style { width: Val::Px(80.0), height: Val::Px(30.0), margin: UiRect::right(Val::Px(10.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(2.0)), ..default() },
             background_color: Color::rgb(0.33, 0.23, 0.15).into(), border_color: BorderColor(Color::BLACK), ..default()
         }, marker
-    )).with_children(|p| { p.spawn(TextBundle::from_section(text, TextStyle { font_size: 16.0, color: Color::rgb(0.9, 0.85, 0.7), ..default() })); });
+    )).with_children(|p| { p.spawn((TextBundle::from_section("", TextStyle { font_size: 16.0, color: Color::rgb(0.9, 0.85, 0.7), ..default() }), Localized(key))); });
 }
 
-fn setup_game_ui(mut commands: Commands, assets: Res<GameAssets>) {
+fn setup_game_ui(mut commands: Commands, atlas: Res<SpriteAtlas>, loc: Res<Localization>) {
     let bar_color = Color::rgb_u8(220, 123, 15);
     commands.spawn((NodeBundle {
         style: Style { width: Val::Percent(100.0), height: Val::Px(100.0), position_type: PositionType::Absolute, bottom: Val::Px(0.0), align_items: AlignItems::Center, padding: UiRect::all(Val::Px(10.0)), ..default() },
         background_color: bar_color.into(), ..default() }, GameComponent,
     )).with_children(|parent| {
-        spawn_ui_button(parent, "MENU", BtnMenu);
-        spawn_ui_button(parent, "PAUSE", BtnPause);
+        // MENU + appel anticipé de la vague en dessous
+        parent.spawn(NodeBundle { style: Style { flex_direction: FlexDirection::Column, ..default() }, ..default() })
+            .with_children(|col| {
+                spawn_ui_button(col, "button.menu", BtnMenu);
+                spawn_call_wave_button(col);
+            });
+        // PAUSE + boutons de vitesse en dessous
+        parent.spawn(NodeBundle { style: Style { flex_direction: FlexDirection::Column, ..default() }, ..default() })
+            .with_children(|col| {
+                spawn_ui_button(col, "button.pause", BtnPause);
+                spawn_speed_buttons(col);
+            });
         parent.spawn(NodeBundle { style: Style { width: Val::Px(20.0), ..default() }, ..default() });
-        spawn_tower_button(parent, &assets, TowerType::Canon);
-        spawn_tower_button(parent, &assets, TowerType::Archer);
-        spawn_tower_button(parent, &assets, TowerType::Wizard);
-        parent.spawn((TextBundle::from_section("Gold: 100\nLives: 3", TextStyle { font_size: 20.0, color: Color::BLACK, ..default() }).with_style(Style { margin: UiRect::left(Val::Px(20.0)), ..default() }), MoneyText));
+        spawn_tower_button(parent, &atlas, &loc, TowerType::Canon);
+        spawn_tower_button(parent, &atlas, &loc, TowerType::Archer);
+        spawn_tower_button(parent, &atlas, &loc, TowerType::Wizard);
+        // Or, vies, vague, score, vitesse (hud.rs)
+        spawn_hud_counters(parent);
+    });
+
+    // Panneau d'info (à droite, au-dessus de la barre du bas)
+    commands.spawn((NodeBundle {
+        style: Style { width: Val::Px(170.0), position_type: PositionType::Absolute, right: Val::Px(10.0), bottom: Val::Px(110.0), padding: UiRect::all(Val::Px(8.0)), border: UiRect::all(Val::Px(2.0)), display: Display::None, ..default() },
+        background_color: Color::rgba(0.33, 0.23, 0.15, 0.9).into(), border_color: BorderColor(Color::BLACK), z_index: ZIndex::Global(50), ..default() }, TowerInfoPanel, GameComponent,
+    )).with_children(|parent| {
+        parent.spawn((TextBundle::from_section("", TextStyle { font_size: 16.0, color: Color::rgb(0.9, 0.85, 0.7), ..default() }), TowerInfoText));
     });
 
     // Overlay Pause
@@ -312,11 +330,11 @@ fn setup_game_ui(mut commands: Commands, assets: Res<GameAssets>) {
         style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), position_type: PositionType::Absolute, justify_content: JustifyContent::Center, align_items: AlignItems::Center, display: Display::None, ..default() },
         background_color: Color::rgba(0.0, 0.0, 0.0, 0.5).into(), z_index: ZIndex::Global(100), ..default() }, PauseOverlay, GameComponent,
     )).with_children(|parent| {
-        parent.spawn(TextBundle::from_section("GAME PAUSED", TextStyle { font_size: 50.0, color: Color::WHITE, ..default() }));
+        parent.spawn((TextBundle::from_section("", TextStyle { font_size: 50.0, color: Color::WHITE, ..default() }), Localized("game.paused")));
     });
 }
 
-fn spawn_tower_button(parent: &mut ChildBuilder, assets: &Res<GameAssets>, tower_type: TowerType) {
+fn spawn_tower_button(parent: &mut ChildBuilder, atlas: &SpriteAtlas, loc: &Localization, tower_type: TowerType) {
     parent.spawn((
         ButtonBundle {
             style: Style {
@@ -337,33 +355,120 @@ fn spawn_tower_button(parent: &mut ChildBuilder, assets: &Res<GameAssets>, tower
         TowerButton { tower_type },
     )).with_children(|parent| {
         // utilisation d'AtlasImageBundle pour afficher JUSTE le sprite
-        parent.spawn(AtlasImageBundle {
+        parent.spawn((AtlasImageBundle {
             style: Style {
                 width: Val::Percent(100.0),  // Remplissage du bouton
                 height: Val::Percent(100.0),
                 ..default()
             },
-            texture_atlas: TextureAtlas {
-                layout: assets.sprite_atlas_layout.clone(),
-                index: tower_type.get_sprite_index(),
-            },
-            image: UiImage::new(assets.sprite_atlas.clone()),
+            texture_atlas: atlas.sprite(tower_type.sprite_name()),
+            image: UiImage::new(atlas.image.clone()),
             ..default()
+        }, TowerButtonIcon));
+
+        // Infobulle au-dessus du bouton, cachée par défaut
+        parent.spawn((NodeBundle {
+            style: Style { position_type: PositionType::Absolute, bottom: Val::Percent(110.0), padding: UiRect::all(Val::Px(5.0)), display: Display::None, ..default() },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.8).into(), z_index: ZIndex::Global(60), ..default() }, TowerTooltip,
+        )).with_children(|p| {
+            p.spawn(TextBundle::from_section(
+                loc.format("tower.tooltip", &[&loc.get(tower_type.name_key()), &tower_type.get_cost()]),
+                TextStyle { font_size: 14.0, color: Color::WHITE, ..default() },
+            ).with_no_wrap());
         });
     });
 }
 
-pub fn spawn_composite_tile(commands: &mut Commands, assets: &Res<GameAssets>, pos: Vec2, base_i: usize, over_i: usize, rot: Quat) {
+// Texte détaillé d'une tour, à partir des stats de base
+fn tower_info_text(loc: &Localization, tower_type: TowerType) -> String {
+    let (range, damage, cooldown) = tower_type.get_base_stats();
+    loc.format("tower.info", &[
+        &loc.get(tower_type.name_key()), &tower_type.get_cost(), &damage, &format!("{:.0}", range),
+        &format!("{:.2}", 1.0 / cooldown), &format!("{:.1}", damage as f32 / cooldown),
+    ])
+}
+
+// Affiche l'infobulle du bouton survolé
+fn update_tower_tooltips(
+    buttons: Query<(&Interaction, &Children), (Changed<Interaction>, With<TowerButton>)>,
+    mut tooltips: Query<&mut Style, With<TowerTooltip>>,
+) {
+    for (interaction, children) in buttons.iter() {
+        for &child in children.iter() {
+            if let Ok(mut style) = tooltips.get_mut(child) {
+                style.display = if *interaction == Interaction::Hovered { Display::Flex } else { Display::None };
+            }
+        }
+    }
+}
+
+// Texte d'une tour posée : stats de son niveau et bilan depuis sa construction
+fn placed_tower_info_text(loc: &Localization, tower_type: TowerType, tower: &Tower, stats: &TowerStats) -> String {
+    let cooldown = tower.cooldown.duration().as_secs_f32();
+    let upgrade = if tower.tier < MAX_TIER { loc.format("tower.upgrade_cost", &[&tower_type.get_upgrade_cost()]) } else { loc.get("tower.upgrade_max").to_string() };
+    loc.format("tower.placed_info", &[
+        &loc.get(tower_type.name_key()), &tower.tier, &tower.damage, &format!("{:.0}", tower.range), &format!("{:.1}", tower.damage as f32 / cooldown),
+        &stats.kills, &stats.damage, &stats.shots, &upgrade, &tower_type.get_sell_value(tower.tier),
+    ])
+}
+
+// Le panneau montre la tour survolée, sinon la tour à construire, sinon la tour posée sélectionnée
+fn update_tower_info_panel(
+    buttons: Query<(&Interaction, &TowerButton)>,
+    selected_tower: Res<SelectedTower>,
+    loc: Res<Localization>,
+    towers: Query<(&TowerType, &Tower, &TowerStats)>,
+    mut panel: Query<&mut Style, With<TowerInfoPanel>>,
+    mut text: Query<&mut Text, With<TowerInfoText>>,
+) {
+    let hovered = buttons.iter().find(|(i, _)| **i != Interaction::None).map(|(_, b)| b.tower_type);
+    let shown = match hovered.or(selected_tower.tower_type) {
+        Some(tower_type) => Some(tower_info_text(&loc, tower_type)),
+        None => selected_tower.placed
+            .and_then(|entity| towers.get(entity).ok())
+            .map(|(tower_type, tower, stats)| placed_tower_info_text(&loc, *tower_type, tower, stats)),
+    };
+
+    for mut style in panel.iter_mut() {
+        style.display = if shown.is_some() { Display::Flex } else { Display::None };
+    }
+    if let Some(value) = shown {
+        for mut t in text.iter_mut() {
+            if t.sections[0].value != value { t.sections[0].value = value.clone(); }
+        }
+    }
+}
+
+// Grise les boutons des tours trop chères
+fn update_tower_affordability(
+    stats: Res<PlayerStats>,
+    mut buttons: Query<(&TowerButton, &Children, &mut BackgroundColor)>,
+    mut icons: Query<&mut BackgroundColor, (With<TowerButtonIcon>, Without<TowerButton>)>,
+) {
+    for (button, children, mut bg) in buttons.iter_mut() {
+        let affordable = stats.money >= button.tower_type.get_cost();
+        let bg_color = if affordable { Color::GRAY } else { Color::DARK_GRAY };
+        if bg.0 != bg_color { bg.0 = bg_color; }
+        let icon_tint = if affordable { Color::WHITE } else { Color::rgba(0.4, 0.4, 0.4, 0.8) };
+        for &child in children.iter() {
+            if let Ok(mut tint) = icons.get_mut(child) && tint.0 != icon_tint {
+                tint.0 = icon_tint;
+            }
+        }
+    }
+}
+
+pub fn spawn_composite_tile(commands: &mut Commands, atlas: &SpriteAtlas, pos: Vec2, base: &str, overlay: &str, rot: Quat) {
      commands.spawn((
         SpriteSheetBundle {
-            texture: assets.sprite_atlas.clone(),
-            atlas: TextureAtlas { layout: assets.sprite_atlas_layout.clone(), index: base_i },
+            texture: atlas.image.clone(),
+            atlas: atlas.sprite(base),
             transform: Transform::from_xyz(pos.x, pos.y, 0.0), ..default()
         }, GameTile { tile_type: TileType::Water }, GameComponent));
     commands.spawn((
         SpriteSheetBundle {
-            texture: assets.sprite_atlas.clone(),
-            atlas: TextureAtlas { layout: assets.sprite_atlas_layout.clone(), index: over_i },
+            texture: atlas.image.clone(),
+            atlas: atlas.sprite(overlay),
             transform: Transform { translation: pos.extend(0.1), rotation: rot, ..default() }, ..default()
         }, GameTile { tile_type: TileType::Water }, GameComponent));
 }
@@ -384,6 +489,7 @@ fn tower_button_interaction(
         match *interaction {
             Interaction::Pressed => {
                 selected_tower.tower_type = Some(tower_button.tower_type);
+                selected_tower.placed = None;
                 *border_color = BorderColor(Color::BLACK);
             }
             Interaction::Hovered => {
@@ -402,79 +508,140 @@ fn tower_button_interaction(
     }
 }
 
-fn update_ui_stats(
-    stats: Res<PlayerStats>,
-    mut money_query: Query<&mut Text, (With<MoneyText>, Without<LivesText>)>,
-    mut lives_query: Query<&mut Text, (With<LivesText>, Without<MoneyText>)>,
-) {
-    // mise a jour du texte
-    for mut text in money_query.iter_mut() {
-        text.sections[0].value = format!("Gold: {}", stats.money);
-    }
-    for mut text in lives_query.iter_mut() {
-        text.sections[0].value = format!("Lives: {}", stats.lives);
-    }
-}
-
 fn ui_button_interaction(
     mut interaction_query: Query<(&Interaction, Option<&BtnMenu>, Option<&BtnPause>), (Changed<Interaction>, With<Button>)>,
     mut next_state: ResMut<NextState<AppState>>,
-    mut pause: ResMut<GlobalPause>,
+    mut speed: ResMut<GameSpeed>,
 ) {
     for (interaction, btn_menu, btn_pause) in interaction_query.iter_mut() {
         if *interaction == Interaction::Pressed {
             if btn_menu.is_some() {
                 next_state.set(AppState::Menu);
-                pause.0 = false; 
             } else if btn_pause.is_some() {
-                pause.0 = !pause.0; // Toggle
+                speed.toggle_pause();
             }
         }
     }
 }
 
 fn update_ui_text(
-    stats: Res<PlayerStats>,
-    pause: Res<GlobalPause>,
-    mut money_query: Query<&mut Text, With<MoneyText>>,
-    mut pause_btn_text: Query<&mut Text, (With<BtnPause>, Without<MoneyText>)>,
+    speed: Res<GameSpeed>,
+    pause_btn: Query<&Children, With<BtnPause>>,
+    mut labels: Query<&mut Localized>,
     mut overlay_query: Query<&mut Style, With<PauseOverlay>>,
 ) {
-    for mut text in money_query.iter_mut() { text.sections[0].value = format!("Gold: {}\nLives: {}", stats.money, stats.lives); }
-    for mut text in pause_btn_text.iter_mut() { text.sections[0].value = if pause.0 { "RESUME".into() } else { "PAUSE".into() }; }
-    for mut style in overlay_query.iter_mut() { style.display = if pause.0 { Display::Flex } else { Display::None }; }
+    // Le texte est un enfant du bouton
+    for children in pause_btn.iter() {
+        let key = if speed.paused { "button.resume" } else { "button.pause" };
+        let mut iter = labels.iter_many_mut(children);
+        while let Some(mut label) = iter.fetch_next() { if label.0 != key { label.0 = key; } }
+    }
+    for mut style in overlay_query.iter_mut() { style.display = if speed.paused { Display::Flex } else { Display::None }; }
 }
 
 // Gère le clic sur la grille pour poser une tour
-fn grid_click_interaction(mut commands: Commands, mouse: Res<ButtonInput<MouseButton>>, win: Query<&Window, With<PrimaryWindow>>, cam: Query<(&Camera, &GlobalTransform)>, sel: Res<SelectedTower>, ass: Res<GameAssets>, mut stats: ResMut<PlayerStats>, pause: Res<GlobalPause>) {
-    if pause.0 || !mouse.just_pressed(MouseButton::Left) { return; } 
-    let Some(tt) = sel.tower_type else { return; };
-    let cost = tt.get_cost();
-    if stats.money < cost { return; }
+// La position est convertie par la caméra : valable quelle que soit la taille de la fenêtre
+fn grid_click_interaction(mut commands: Commands, mouse: Res<ButtonInput<MouseButton>>, win: Query<&Window, With<PrimaryWindow>>, cam: Query<(&Camera, &GlobalTransform), With<MainCamera>>, ui: Query<&Interaction>, mut sel: ResMut<SelectedTower>, atlas: Res<SpriteAtlas>, mut stats: ResMut<PlayerStats>, mut ledger: ResMut<Ledger>, speed: Res<GameSpeed>, towers: Query<(Entity, &Transform), With<Tower>>, mut built: EventWriter<TowerBuilt>, mut denied: EventWriter<PurchaseDenied>) {
+    if speed.paused || !mouse.just_pressed(MouseButton::Left) { return; } 
+    // Clic sur un élément d'interface (boutons, HUD) : pas pour la grille
+    if ui.iter().any(|i| *i != Interaction::None) { return; }
     let (cam, c_trans) = cam.single();
     let Some(w) = win.get_single().ok() else { return; };
-    if let Some(w_pos) = w.cursor_position().and_then(|c| cam.viewport_to_world(c_trans, c)).map(|r| r.origin.truncate()) {
-        if w_pos.y < (-370.0 + 100.0) { return; } 
-        let ts = 32.0;
-        let x_off = -20.0 * ts / 2.0 + ts/2.0;
-        let y_off = (20.0 * ts / 2.0 - ts/2.0) + 50.0;
-        let gx = ((w_pos.x - x_off)/ts).round();
-        let gy = ((y_off - w_pos.y)/ts).round();
-        if gx>=0.0 && gx<20.0 && gy>=0.0 && gy<20.0 {
-             let lvl = level::get_level_data();
-             if lvl[gy as usize][gx as usize] == 0 {
-                 let snap = Vec2::new(x_off + gx*ts, y_off - gy*ts);
-                 let (rng, dmg, cd) = tt.get_base_stats();
-                 commands.spawn((
-                     SpriteSheetBundle {
-                         texture: ass.sprite_atlas.clone(),
-                         atlas: TextureAtlas { layout: ass.sprite_atlas_layout.clone(), index: tt.get_sprite_index() },
-                         transform: Transform::from_xyz(snap.x, snap.y, 2.0), ..default()
-                     }, Tower { range: rng, damage: dmg, cooldown: Timer::from_seconds(cd, TimerMode::Repeating) }, GameComponent
-                 ));
-                 stats.money -= cost;
-             }
+    let Some(w_pos) = w.cursor_position().and_then(|c| cam.viewport_to_world(c_trans, c)).map(|r| r.origin.truncate()) else { return; };
+    let Some((gx, gy)) = level::world_to_tile(w_pos) else { return; };
+    let snap = level::tile_to_world(gx, gy);
+
+    // Clic sur une tour existante -> on la sélectionne (pour l'améliorer ou la vendre)
+    if let Some((e, _)) = towers.iter().find(|(_, t)| t.translation.truncate().distance(snap) < 1.0) {
+        sel.placed = Some(e);
+        sel.tower_type = None;
+        return;
+    }
+    sel.placed = None;
+    let Some(tt) = sel.tower_type else { return; };
+    // Seulement sur l'herbe
+    if level::get_level_data()[gy][gx] != 0 { return; }
+    let cost = tt.get_cost();
+    if stats.money < cost {
+        denied.send(PurchaseDenied { cost });
+        return;
+    }
+    let tower = spawn_tower(&mut commands, &atlas, tt, Tower::new(tt, 1), snap);
+    ledger.spend(&mut stats, LedgerEntry::TowerPurchase, cost);
+    built.send(TowerBuilt { tower, tower_type: tt });
+}
+
+// Pose une tour du jeu (clic sur la grille ou chargement de partie)
+pub fn spawn_tower(commands: &mut Commands, atlas: &SpriteAtlas, tower_type: TowerType, tower: Tower, position: Vec2) -> Entity {
+    commands.spawn((
+        SpriteSheetBundle {
+            texture: atlas.image.clone(),
+            atlas: atlas.sprite(tower_type.sprite_name()),
+            transform: Transform::from_xyz(position.x, position.y, 2.0), ..default()
+        }, tower, tower_type, TowerStats::default(), SpriteAnimator::default(), GameComponent
+    )).id()
+}
+
+// Actions clavier (voir input.rs pour les touches)
+fn keyboard_actions(
+    mut commands: Commands,
+    actions: Res<ActionState>,
+    mut sel: ResMut<SelectedTower>,
+    mut stats: ResMut<PlayerStats>,
+    mut ledger: ResMut<Ledger>,
+    mut sold: ResMut<SoldTowerStats>,
+    mut speed: ResMut<GameSpeed>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut towers: Query<(&mut Tower, &TowerType, &TowerStats)>,
+    mut denied: EventWriter<PurchaseDenied>,
+) {
+    for (action, tower_type) in [
+        (InputAction::SelectTower1, TowerType::Canon),
+        (InputAction::SelectTower2, TowerType::Archer),
+        (InputAction::SelectTower3, TowerType::Wizard),
+    ] {
+        if actions.just_pressed(action) {
+            sel.tower_type = Some(tower_type);
+            sel.placed = None;
+        }
+    }
+    if actions.just_pressed(InputAction::CancelSelection) {
+        sel.tower_type = None;
+        sel.placed = None;
+    }
+    if actions.just_pressed(InputAction::Pause) {
+        speed.toggle_pause();
+    }
+    if actions.just_pressed(InputAction::BackToMenu) {
+        next_state.set(AppState::Menu);
+    }
+
+    // Amélioration / vente de la tour posée sélectionnée
+    if speed.paused { return; }
+    let Some(entity) = sel.placed else { return; };
+    let Ok((mut tower, tower_type, tower_stats)) = towers.get_mut(entity) else { sel.placed = None; return; };
+    if actions.just_pressed(InputAction::Upgrade) && tower.tier < MAX_TIER {
+        let cost = tower_type.get_upgrade_cost();
+        if stats.money >= cost {
+            ledger.spend(&mut stats, LedgerEntry::TowerUpgrade, cost);
+            tower.upgrade(*tower_type);
+        } else {
+            denied.send(PurchaseDenied { cost });
         }
+    } else if actions.just_pressed(InputAction::Sell) {
+        ledger.earn(&mut stats, LedgerEntry::TowerSale, tower_type.get_sell_value(tower.tier));
+        // Son bilan reste compté dans le résumé de fin de partie
+        sold.add(*tower_type, *tower_stats);
+        commands.entity(entity).despawn_recursive();
+        sel.placed = None;
+    }
+}
+
+// Teinte jaune sur la tour posée sélectionnée
+fn highlight_selected_tower(sel: Res<SelectedTower>, mut towers: Query<(Entity, &mut Sprite), With<Tower>>) {
+    for (entity, mut sprite) in towers.iter_mut() {
+        let color = if sel.placed == Some(entity) { Color::rgb(1.0, 1.0, 0.6) } else { Color::WHITE };
+        if sprite.color != color { sprite.color = color; }
     }
 }
 
@@ -499,8 +666,9 @@ pub fn get_tile_type(tile_id: u32) -> TileType {
     }
 }
 
-fn cleanup_game(
+pub fn cleanup_game(
     mut cmd: Commands, 
+    mut speed: ResMut<GameSpeed>,
     // Récupération de toutes les entités du jeu
     all_enemies: Query<Entity, With<Enemy>>,
     all_towers: Query<Entity, With<Tower>>,
@@ -518,29 +686,120 @@ fn cleanup_game(
     cmd.remove_resource::<Path>();
     cmd.remove_resource::<SelectedTower>();
     cmd.remove_resource::<PlayerStats>();
+    cmd.remove_resource::<WaveManager>();
+    cmd.remove_resource::<Score>();
+    cmd.remove_resource::<Ledger>();
+    cmd.remove_resource::<SoldTowerStats>();
+    speed.reset(SpeedSetting::Normal);
+}
+
+// Tirs de chaque tour (les tours de la simulation n'ont pas de TowerStats)
+fn count_shots(mut events: EventReader<TowerFired>, mut towers: Query<&mut TowerStats>, mut sold: ResMut<SoldTowerStats>) {
+    for event in events.read() {
+        match towers.get_mut(event.tower) {
+            Ok(mut stats) => stats.shots += 1,
+            // Tour vendue dans la frame du tir
+            Err(_) => sold.credit(event.tower_type, TowerStats { shots: 1, ..default() }),
+        }
+    }
+}
+
+// Une vie perdue par ennemi arrivé au bout
+fn apply_leak_damage(mut events: EventReader<EnemyLeaked>, mut stats: ResMut<PlayerStats>) {
+    for _ in events.read() {
+        stats.lives -= 1;
+    }
 }
 
-pub fn tower_shooting(mut commands: Commands, assets: Res<GameAssets>, time: Res<Time>, mut tower_query: Query<(&Transform, &mut Tower)>, enemy_query: Query<(Entity, &Transform), With<Enemy>>) {
-    for (t_trans, mut tower) in tower_query.iter_mut() {
+pub fn tower_shooting(mut commands: Commands, atlas: Res<SpriteAtlas>, time: Res<Time>, mut fired: EventWriter<TowerFired>, mut tower_query: Query<(Entity, &Transform, &mut Tower, &TowerType)>, mut enemy_query: Query<(Entity, &Transform, &Health, &mut PendingDamage, &Velocity), With<Enemy>>) {
+    for (t_ent, t_trans, mut tower, tower_type) in tower_query.iter_mut() {
         tower.cooldown.tick(time.delta());
         if tower.cooldown.just_finished() {
             let t_pos = t_trans.translation.truncate();
             let mut closest = None;
             let mut min_sq = tower.range * tower.range;
-            for (e_ent, e_trans) in enemy_query.iter() {
+            for (e_ent, e_trans, health, pending, _) in enemy_query.iter() {
+                // Les projectiles en vol suffisent déjà à le tuer : inutile de gaspiller un tir
+                if pending.is_doomed(health) { continue; }
                 let d_sq = t_pos.distance_squared(e_trans.translation.truncate());
                 if d_sq <= min_sq { min_sq = d_sq; closest = Some(e_ent); }
             }
             if let Some(target) = closest {
-                commands.spawn((
-                    SpriteSheetBundle {
-                        texture: assets.sprite_atlas.clone(),
-                        atlas: TextureAtlas { layout: assets.sprite_atlas_layout.clone(), index: 17 },
-                        transform: Transform::from_xyz(t_pos.x, t_pos.y, 2.0), ..default()
-                    }, Projectile { target, damage: tower.damage, speed: 300.0 }, GameComponent
-                ));
+                let projectile_type = tower_type.get_projectile_type();
+                // Vitesse Java en pixels par tick (60 UPS) -> pixels par seconde
+                let speed = ProjectileConstants::get_speed(projectile_type) * 60.0;
+                let Ok((_, e_trans, _, mut pending, velocity)) = enemy_query.get_mut(target) else { continue; };
+                pending.0 += tower.damage;
+                // Tir anticipé : on vise là où l'ennemi sera à l'arrivée du projectile
+                let destination = predict_intercept(t_pos, e_trans.translation.truncate(), velocity.0, speed);
+                spawn_projectile(&mut commands, &atlas, t_pos, Projectile { target, source: t_ent, source_type: *tower_type, damage: tower.damage, speed, projectile_type, destination });
+                fired.send(TowerFired { tower: t_ent, tower_type: *tower_type, target, direction: (destination - t_pos).normalize_or_zero() });
                 tower.cooldown.reset();
             }
         }
     }
-}
\ No newline at end of file
+}
+
+// Lance un projectile depuis une position (tir d'une tour ou chargement de partie)
+pub fn spawn_projectile(commands: &mut Commands, atlas: &SpriteAtlas, position: Vec2, projectile: Projectile) -> Entity {
+    let direction = projectile.destination - position;
+    commands.spawn((
+        SpriteSheetBundle {
+            texture: atlas.image.clone(),
+            atlas: atlas.sprite(ProjectileConstants::get_sprite_name(projectile.projectile_type)),
+            transform: Transform::from_xyz(position.x, position.y, 2.0).with_rotation(Quat::from_rotation_z(direction.y.atan2(direction.x))), ..default()
+        }, projectile, GameComponent
+    )).id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Case de chaque sprite de tuile dans assets/atlas/spriteatlas.atlas.ron
+    fn cell(name: &str) -> usize {
+        let (x, y) = match name {
+            "water" => (0, 0),
+            "shore_inner" => (4, 0),
+            "shore_corner" => (5, 0),
+            "shore_edge" => (6, 0),
+            "road_corner" => (7, 0),
+            "road" => (8, 0),
+            "grass" => (9, 0),
+            "start" => (7, 2),
+            "end" => (8, 2),
+            _ => panic!("sprite de tuile inconnu : {name}"),
+        };
+        y * 10 + x
+    }
+
+    // Ancienne correspondance (avant l'atlas nommé) : (fond, calque, rotation en degrés), index = y * 10 + x
+    fn old_tile(tile_id: u32) -> (usize, Option<usize>, f32) {
+        match tile_id {
+            2 => (8, None, 0.0),
+            3 => (8, None, -90.0),
+            4 => (7, None, 0.0),
+            5 => (7, None, -90.0),
+            6 => (7, None, 180.0),
+            7 => (7, None, 90.0),
+            8..=11 => (0, Some(5), [0.0, -90.0, 180.0, 90.0][(tile_id - 8) as usize]),
+            12..=15 => (0, Some(6), [0.0, -90.0, 180.0, 90.0][(tile_id - 12) as usize]),
+            16..=19 => (0, Some(4), [0.0, -90.0, 180.0, 90.0][(tile_id - 16) as usize]),
+            20 => (8, Some(27), 0.0),
+            21 => (8, Some(28), 0.0),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn tile_sprites_match_old_atlas_mapping() {
+        for tile_id in 2..=21 {
+            let (base, overlay, degrees) = old_tile(tile_id);
+            let sprites = tile_sprites(tile_id);
+            assert_eq!(cell(sprites.base), base, "fond de la tuile {tile_id}");
+            assert_eq!(sprites.overlay.map(cell), overlay, "calque de la tuile {tile_id}");
+            let expected = Quat::from_rotation_z(degrees.to_radians());
+            assert!(sprites.rotation.abs_diff_eq(expected, 1e-5) || sprites.rotation.abs_diff_eq(-expected, 1e-5), "rotation de la tuile {tile_id}");
+        }
+    }
+}
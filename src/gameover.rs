@@ -0,0 +1,234 @@
+// src/gameover.rs
+// Fin de partie (équivalent de GameOver.java) : défaite quand les vies tombent à 0,
+// victoire quand toutes les vagues sont passées ; écran récapitulatif du score
+
+use std::collections::BTreeMap;
+use bevy::prelude::*;
+use crate::{
+    AppState,
+    level,
+    game::PlayerStats,
+    wave::{WaveManager, ReplaySeed},
+    difficulty::Difficulty,
+    tower::{TowerType, TowerStats, SoldTowerStats},
+    i18n::Localization,
+    score::{Score, ScoreBreakdown},
+    profile::{PlayerProfile, HighScore},
+    menu::{NORMAL_BUTTON, HOVERED_BUTTON, PRESSED_BUTTON},
+};
+
+// Résultat de la partie qui vient de se terminer, affiché par l'écran de fin
+#[derive(Resource)]
+pub struct RunSummary {
+    pub victory: bool,
+    pub waves_cleared: usize,
+    pub wave_count: Option<usize>, // None en mode Endless
+    pub seed: Option<u64>,         // Graine des vagues Endless, pour rejouer la même partie
+    pub score: ScoreBreakdown,
+    pub rank: Option<usize>, // Place dans le tableau des meilleurs scores
+    pub towers: Vec<(TowerType, u32, TowerStats)>, // Par type : nombre de tours (vendues comprises), stats cumulées
+}
+
+#[derive(Component)]
+struct GameOverUI;
+
+#[derive(Component)]
+enum GameOverButton {
+    PlayAgain,
+    SameSeed,
+    Menu,
+}
+
+pub struct GameOverPlugin;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Après Update : les bonus de la dernière vague sont déjà comptés (score_waves et pay_wave_income passent après update_waves)
+            .add_systems(PostUpdate, check_end_of_run.run_if(in_state(AppState::Playing)))
+            .add_systems(OnEnter(AppState::GameOver), setup_game_over)
+            .add_systems(Update, game_over_buttons.run_if(in_state(AppState::GameOver)))
+            .add_systems(OnExit(AppState::GameOver), cleanup_game_over);
+    }
+}
+
+fn check_end_of_run(
+    mut commands: Commands,
+    stats: Res<PlayerStats>,
+    waves: Res<WaveManager>,
+    score: Res<Score>,
+    sold_towers: Res<SoldTowerStats>,
+    towers: Query<(&TowerType, &TowerStats)>,
+    mut profile: ResMut<PlayerProfile>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let defeat = stats.lives <= 0;
+    if !defeat && !waves.is_finished() { return; }
+
+    let breakdown = score.breakdown(&stats);
+    // Le profil est écrit en sortant de Playing (profile.rs)
+    let rank = profile.record_high_score(HighScore {
+        level: level::LEVEL_NAME.to_string(),
+        score: breakdown.total(),
+        waves: waves.current as u32,
+        victory: !defeat,
+    });
+    let mut by_type: BTreeMap<TowerType, (u32, TowerStats)> = sold_towers.0.clone();
+    for (tower_type, stats) in towers.iter() {
+        let entry = by_type.entry(*tower_type).or_default();
+        entry.0 += 1;
+        entry.1 += *stats;
+    }
+    commands.insert_resource(RunSummary {
+        victory: !defeat,
+        waves_cleared: waves.current,
+        wave_count: waves.wave_count(),
+        seed: waves.seed,
+        score: breakdown,
+        rank,
+        towers: by_type.into_iter().map(|(tower_type, (count, stats))| (tower_type, count, stats)).collect(),
+    });
+    next_state.set(AppState::GameOver);
+}
+
+fn setup_game_over(mut commands: Commands, summary: Res<RunSummary>, difficulty: Res<Difficulty>, loc: Res<Localization>) {
+    let text_style = TextStyle { font_size: 20.0, color: Color::WHITE, ..default() };
+    let (title, title_color) = if summary.victory {
+        (loc.get("gameover.victory"), Color::rgb(0.4, 0.9, 0.4))
+    } else {
+        (loc.get("gameover.defeat"), Color::rgb(0.9, 0.3, 0.3))
+    };
+    let score = summary.score;
+    let waves_cleared = match summary.wave_count {
+        Some(count) => format!("{}/{}", summary.waves_cleared, count),
+        None => summary.waves_cleared.to_string(),
+    };
+    let mut rows = vec![
+        ("gameover.difficulty", loc.get(difficulty.key()).to_string()),
+        ("gameover.waves_cleared", waves_cleared),
+        ("gameover.kills", score.kills.to_string()),
+        ("gameover.waves", score.waves.to_string()),
+        ("gameover.early_calls", score.early_calls.to_string()),
+        ("gameover.lives_left", score.lives.to_string()),
+        ("gameover.unspent_gold", score.gold.to_string()),
+    ];
+    if let Some(seed) = summary.seed {
+        rows.push(("gameover.seed", seed.to_string()));
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.1, 0.1, 0.1).into(),
+            ..default()
+        },
+        GameOverUI,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(title, TextStyle { font_size: 48.0, color: title_color, ..default() })
+            .with_style(Style { margin: UiRect::bottom(Val::Px(20.0)), ..default() }));
+
+        for (label, value) in rows {
+            parent.spawn(NodeBundle {
+                style: Style { width: Val::Px(300.0), justify_content: JustifyContent::SpaceBetween, ..default() },
+                ..default()
+            }).with_children(|row| {
+                row.spawn(TextBundle::from_section(loc.get(label), text_style.clone()));
+                row.spawn(TextBundle::from_section(value, text_style.clone()));
+            });
+        }
+
+        // Bilan des tours par type : quelles tours ont vraiment travaillé
+        if !summary.towers.is_empty() {
+            let small = TextStyle { font_size: 16.0, ..text_style.clone() };
+            let header = TextStyle { color: Color::rgb(0.9, 0.75, 0.4), ..small.clone() };
+            let mut table = vec![(["gameover.tower", "gameover.kills", "gameover.damage", "gameover.shots"].map(|key| loc.get(key).to_string()), header)];
+            for (tower_type, count, stats) in &summary.towers {
+                table.push(([
+                    format!("{} x{}", loc.get(tower_type.name_key()), count),
+                    stats.kills.to_string(),
+                    stats.damage.to_string(),
+                    stats.shots.to_string(),
+                ], small.clone()));
+            }
+            parent.spawn(NodeBundle { style: Style { margin: UiRect::top(Val::Px(12.0)), flex_direction: FlexDirection::Column, ..default() }, ..default() })
+                .with_children(|grid| {
+                    for (cells, style) in table {
+                        grid.spawn(NodeBundle::default()).with_children(|row| {
+                            for (i, cell) in cells.into_iter().enumerate() {
+                                let width = if i == 0 { 170.0 } else { 70.0 };
+                                row.spawn(NodeBundle { style: Style { width: Val::Px(width), justify_content: if i == 0 { JustifyContent::FlexStart } else { JustifyContent::FlexEnd }, ..default() }, ..default() })
+                                    .with_children(|c| { c.spawn(TextBundle::from_section(cell, style.clone())); });
+                            }
+                        });
+                    }
+                });
+        }
+
+        parent.spawn(TextBundle::from_section(loc.format("gameover.score", &[&score.total()]), TextStyle { font_size: 32.0, ..text_style.clone() })
+            .with_style(Style { margin: UiRect::top(Val::Px(16.0)), ..default() }));
+        let rank_text = match summary.rank {
+            Some(0) => loc.get("gameover.new_high_score").to_string(),
+            Some(rank) => loc.format("gameover.high_score_rank", &[&(rank + 1)]),
+            None => String::new(),
+        };
+        parent.spawn(TextBundle::from_section(rank_text, TextStyle { color: Color::rgb(0.9, 0.75, 0.4), ..text_style.clone() }));
+
+        parent.spawn(NodeBundle { style: Style { margin: UiRect::top(Val::Px(20.0)), ..default() }, ..default() })
+            .with_children(|row| {
+                let mut buttons = vec![("gameover.play_again", GameOverButton::PlayAgain)];
+                if summary.seed.is_some() { buttons.push(("gameover.same_seed", GameOverButton::SameSeed)); }
+                buttons.push(("button.menu", GameOverButton::Menu));
+                for (label, action) in buttons {
+                    row.spawn((
+                        ButtonBundle {
+                            style: Style { width: Val::Px(150.0), height: Val::Px(40.0), margin: UiRect::all(Val::Px(6.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, ..default() },
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        action,
+                    )).with_children(|b| {
+                        b.spawn(TextBundle::from_section(loc.get(label), text_style.clone()));
+                    });
+                }
+            });
+    });
+}
+
+fn game_over_buttons(
+    mut commands: Commands,
+    summary: Res<RunSummary>,
+    mut query: Query<(&Interaction, &GameOverButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, action, mut color) in query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                // Les vagues Endless de la partie suivante reprennent la même graine (lue par setup_game)
+                if let (GameOverButton::SameSeed, Some(seed)) = (action, summary.seed) {
+                    commands.insert_resource(ReplaySeed(seed));
+                }
+                next_state.set(match action {
+                    GameOverButton::PlayAgain | GameOverButton::SameSeed => AppState::Playing,
+                    GameOverButton::Menu => AppState::Menu,
+                });
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+fn cleanup_game_over(mut commands: Commands, query: Query<Entity, With<GameOverUI>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<RunSummary>();
+}
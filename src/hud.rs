@@ -0,0 +1,166 @@
+// src/hud.rs
+// Compteurs du HUD (barre du bas) : or, vies, vague, score et vitesse
+// Chaque compteur a son icône et "pulse" quand sa valeur change (vert si elle monte, rouge si elle baisse)
+
+use bevy::prelude::*;
+use crate::{
+    AppState,
+    game::PlayerStats,
+    wave::WaveManager,
+    score::Score,
+    speed::GameSpeed,
+    effects::ShakeOnLeak,
+    i18n::Localization,
+};
+
+const PULSE_TIME: f32 = 0.35;
+const PULSE_SCALE: f32 = 1.3;
+const TEXT_COLOR: Color = Color::BLACK;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HudStat {
+    Gold,
+    Lives,
+    Wave,
+    Score,
+    Speed,
+}
+
+impl HudStat {
+    // Clé du libellé traduit (i18n.rs)
+    fn key(&self) -> &'static str {
+        match self {
+            HudStat::Gold => "hud.gold",
+            HudStat::Lives => "hud.lives",
+            HudStat::Wave => "hud.wave",
+            HudStat::Score => "hud.score",
+            HudStat::Speed => "hud.speed",
+        }
+    }
+
+    fn icon_color(&self) -> Color {
+        match self {
+            HudStat::Gold => Color::GOLD,
+            HudStat::Lives => Color::rgb(0.85, 0.1, 0.1),
+            HudStat::Wave => Color::rgb(0.45, 0.3, 0.15),
+            HudStat::Score => Color::rgb(0.2, 0.45, 0.85),
+            HudStat::Speed => Color::rgb(0.85, 0.85, 0.85),
+        }
+    }
+
+    // Une montée est une bonne nouvelle pour l'or, les vies et le score
+    fn tracks_gain(&self) -> bool {
+        matches!(self, HudStat::Gold | HudStat::Lives | HudStat::Score)
+    }
+}
+
+#[derive(Component)]
+struct HudCounter {
+    stat: HudStat,
+    last: Option<i64>, // Dernière valeur affichée (None avant le premier affichage)
+    pulse: Timer,
+    pulse_color: Color,
+}
+
+// Compteur d'or : survolé, il affiche le registre de l'économie (economy.rs)
+#[derive(Component)]
+pub struct GoldCounter;
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (update_hud_counters, animate_hud_counters).chain().run_if(in_state(AppState::Playing)));
+    }
+}
+
+// Deux colonnes de compteurs dans la barre du bas
+pub fn spawn_hud_counters(parent: &mut ChildBuilder) {
+    for column in [&[HudStat::Gold, HudStat::Lives, HudStat::Wave][..], &[HudStat::Score, HudStat::Speed][..]] {
+        parent.spawn(NodeBundle { style: Style { flex_direction: FlexDirection::Column, margin: UiRect::left(Val::Px(12.0)), ..default() }, ..default() })
+            .with_children(|col| {
+                for &stat in column {
+                    let mut row = col.spawn(NodeBundle { style: Style { align_items: AlignItems::Center, margin: UiRect::vertical(Val::Px(2.0)), ..default() }, ..default() });
+                    match stat {
+                        HudStat::Gold => { row.insert((Interaction::default(), GoldCounter)); }
+                        HudStat::Lives => { row.insert(ShakeOnLeak::default()); }
+                        _ => {}
+                    }
+                    row.with_children(|row| {
+                        row.spawn(NodeBundle {
+                            style: Style { width: Val::Px(10.0), height: Val::Px(10.0), margin: UiRect::right(Val::Px(4.0)), border: UiRect::all(Val::Px(1.0)), ..default() },
+                            background_color: stat.icon_color().into(), border_color: BorderColor(Color::BLACK), ..default()
+                        });
+                        row.spawn((
+                            TextBundle::from_section("", TextStyle { font_size: 14.0, color: TEXT_COLOR, ..default() }).with_no_wrap(),
+                            HudCounter { stat, last: None, pulse: Timer::from_seconds(PULSE_TIME, TimerMode::Once), pulse_color: TEXT_COLOR },
+                        ));
+                    });
+                }
+            });
+    }
+}
+
+fn update_hud_counters(
+    stats: Res<PlayerStats>,
+    waves: Res<WaveManager>,
+    score: Res<Score>,
+    speed: Res<GameSpeed>,
+    loc: Res<Localization>,
+    mut query: Query<(&mut HudCounter, &mut Text)>,
+) {
+    for (mut counter, mut text) in query.iter_mut() {
+        // (valeur comparée d'une frame à l'autre, texte affiché)
+        let (value, shown) = match counter.stat {
+            HudStat::Gold => (stats.money as i64, stats.money.to_string()),
+            HudStat::Lives => (stats.lives as i64, stats.lives.max(0).to_string()),
+            HudStat::Wave => (waves.wave_number() as i64, waves.wave_label()),
+            HudStat::Score => {
+                let total = score.breakdown(&stats).total();
+                (total as i64, total.to_string())
+            }
+            HudStat::Speed if speed.paused => (0, loc.get("hud.paused").into()),
+            HudStat::Speed => (speed.setting.multiplier() as i64, speed.setting.label().into()),
+        };
+        // Changement de langue : nouveau texte, sans animation
+        if counter.last == Some(value) {
+            if loc.is_changed() { text.sections[0].value = format!("{} {}", loc.get(counter.stat.key()), shown); }
+            continue;
+        }
+
+        // Pas d'animation au premier affichage
+        if let Some(last) = counter.last {
+            counter.pulse_color = match (counter.stat.tracks_gain(), value > last) {
+                (true, true) => Color::rgb(0.0, 0.55, 0.0),
+                (true, false) => Color::rgb(0.75, 0.0, 0.0),
+                (false, _) => Color::WHITE,
+            };
+            counter.pulse.reset();
+        } else {
+            let duration = counter.pulse.duration();
+            counter.pulse.tick(duration);
+        }
+        counter.last = Some(value);
+        text.sections[0].value = format!("{} {}", loc.get(counter.stat.key()), shown);
+    }
+}
+
+// En temps réel pour que l'animation se joue aussi en pause (changement de vitesse)
+fn animate_hud_counters(time: Res<Time<Real>>, mut query: Query<(&mut HudCounter, &mut Text, &mut Transform)>) {
+    for (mut counter, mut text, mut transform) in query.iter_mut() {
+        if counter.pulse.finished() {
+            if transform.scale != Vec3::ONE { transform.scale = Vec3::ONE; }
+            if text.sections[0].style.color != TEXT_COLOR { text.sections[0].style.color = TEXT_COLOR; }
+            continue;
+        }
+        counter.pulse.tick(time.delta());
+        let t = counter.pulse.fraction();
+        transform.scale = Vec3::splat(PULSE_SCALE + (1.0 - PULSE_SCALE) * t);
+        let from = counter.pulse_color;
+        text.sections[0].style.color = Color::rgb(
+            from.r() + (TEXT_COLOR.r() - from.r()) * t,
+            from.g() + (TEXT_COLOR.g() - from.g()) * t,
+            from.b() + (TEXT_COLOR.b() - from.b()) * t,
+        );
+    }
+}
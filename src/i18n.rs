@@ -0,0 +1,152 @@
+// src/i18n.rs
+// Traduction de l'interface : une table de chaînes par langue (assets/lang/*.lang.ron),
+// les textes fixes portent un composant Localized et sont mis à jour quand la langue change.
+// Une clé absente de la langue choisie retombe sur l'anglais, puis sur la clé elle-même
+
+use std::{collections::HashMap, fmt::Display};
+use bevy::{
+    prelude::*,
+    asset::{AssetLoader, AsyncReadExt, LoadContext, io::Reader},
+    ui::UiSystem,
+    utils::BoxedFuture,
+};
+use serde::{Serialize, Deserialize};
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::French];
+
+    fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+        }
+    }
+
+    // Toujours affiché dans sa propre langue
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+        }
+    }
+
+    pub fn next(&self) -> Language {
+        match self {
+            Language::English => Language::French,
+            Language::French => Language::English,
+        }
+    }
+}
+
+// Contenu d'un fichier de langue : clé -> texte
+#[derive(Asset, TypePath, Debug, Deserialize)]
+#[serde(transparent)]
+pub struct StringTable(HashMap<String, String>);
+
+#[derive(Default)]
+struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    type Asset = StringTable;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn load<'a>(&'a self, reader: &'a mut Reader, _settings: &'a (), _load_context: &'a mut LoadContext) -> BoxedFuture<'a, Result<StringTable, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(ron::de::from_bytes(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["lang.ron"]
+    }
+}
+
+// Textes de la langue courante (complétés par l'anglais)
+#[derive(Resource, Default)]
+pub struct Localization {
+    tables: HashMap<Language, Handle<StringTable>>,
+    strings: HashMap<String, String>,
+}
+
+impl Localization {
+    // Fichiers de langue (suivis par l'écran de chargement)
+    pub fn tables(&self) -> impl Iterator<Item = &Handle<StringTable>> {
+        self.tables.values()
+    }
+
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings.get(key).map(String::as_str).unwrap_or(key)
+    }
+
+    // Remplace {0}, {1}... par les arguments (l'ordre peut changer d'une langue à l'autre)
+    pub fn format(&self, key: &str, args: &[&dyn Display]) -> String {
+        let mut value = self.get(key).to_string();
+        for (i, arg) in args.iter().enumerate() {
+            value = value.replace(&format!("{{{i}}}"), &arg.to_string());
+        }
+        value
+    }
+}
+
+// Texte fixe traduit : la clé peut changer (ex. PAUSE / RESUME), le texte suit
+#[derive(Component)]
+pub struct Localized(pub &'static str);
+
+pub struct I18nPlugin;
+
+impl Plugin for I18nPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<StringTable>()
+            .register_asset_loader(StringTableLoader)
+            .init_resource::<Language>()
+            .init_resource::<Localization>()
+            .add_systems(Startup, load_string_tables)
+            .add_systems(Update, refresh_strings)
+            // Avant la mise en page : un texte créé pendant la frame s'affiche déjà traduit
+            .add_systems(PostUpdate, update_localized_texts.before(UiSystem::Layout));
+    }
+}
+
+fn load_string_tables(asset_server: Res<AssetServer>, mut localization: ResMut<Localization>) {
+    for language in Language::ALL {
+        localization.tables.insert(language, asset_server.load(format!("lang/{}.lang.ron", language.code())));
+    }
+}
+
+// Reconstruit les textes quand la langue change ou qu'un fichier de langue est (re)chargé
+fn refresh_strings(
+    mut events: EventReader<AssetEvent<StringTable>>,
+    language: Res<Language>,
+    tables: Res<Assets<StringTable>>,
+    mut localization: ResMut<Localization>,
+) {
+    let reloaded = events.read().any(|e| matches!(e, AssetEvent::LoadedWithDependencies { .. } | AssetEvent::Modified { .. }));
+    if !reloaded && !language.is_changed() { return; }
+
+    let mut strings = HashMap::new();
+    for lang in [Language::English, *language] {
+        if let Some(table) = localization.tables.get(&lang).and_then(|h| tables.get(h)) {
+            strings.extend(table.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+    }
+    localization.strings = strings;
+}
+
+fn update_localized_texts(localization: Res<Localization>, mut texts: Query<(Ref<Localized>, &mut Text)>) {
+    for (localized, mut text) in texts.iter_mut() {
+        if !localization.is_changed() && !localized.is_changed() { continue; }
+        let value = localization.get(localized.0);
+        if text.sections[0].value != value { text.sections[0].value = value.to_string(); }
+    }
+}
@@ -0,0 +1,202 @@
+// src/input.rs
+// Couche d'actions : le jeu réagit à des InputAction, jamais directement à des touches
+
+use std::{collections::HashMap, path::PathBuf};
+use bevy::{prelude::*, input::InputSystem, utils::HashSet};
+use serde::{Serialize, Deserialize};
+use crate::storage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    SelectTower1,
+    SelectTower2,
+    SelectTower3,
+    Pause,
+    CancelSelection,
+    Upgrade,
+    Sell,
+    SpeedUp,
+    FrameStep,
+    CallWave,
+    BackToMenu,
+    PanUp,
+    PanDown,
+    PanLeft,
+    PanRight,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 15] = [
+        InputAction::SelectTower1,
+        InputAction::SelectTower2,
+        InputAction::SelectTower3,
+        InputAction::Pause,
+        InputAction::CancelSelection,
+        InputAction::Upgrade,
+        InputAction::Sell,
+        InputAction::SpeedUp,
+        InputAction::FrameStep,
+        InputAction::CallWave,
+        InputAction::BackToMenu,
+        InputAction::PanUp,
+        InputAction::PanDown,
+        InputAction::PanLeft,
+        InputAction::PanRight,
+    ];
+
+    // Clé du libellé traduit (i18n.rs)
+    pub fn key(&self) -> &'static str {
+        match self {
+            InputAction::SelectTower1 => "action.select_tower1",
+            InputAction::SelectTower2 => "action.select_tower2",
+            InputAction::SelectTower3 => "action.select_tower3",
+            InputAction::Pause => "action.pause",
+            InputAction::CancelSelection => "action.cancel_selection",
+            InputAction::Upgrade => "action.upgrade",
+            InputAction::Sell => "action.sell",
+            InputAction::SpeedUp => "action.speed_up",
+            InputAction::FrameStep => "action.frame_step",
+            InputAction::CallWave => "action.call_wave",
+            InputAction::BackToMenu => "action.back_to_menu",
+            InputAction::PanUp => "action.pan_up",
+            InputAction::PanDown => "action.pan_down",
+            InputAction::PanLeft => "action.pan_left",
+            InputAction::PanRight => "action.pan_right",
+        }
+    }
+
+    pub fn default_key(&self) -> KeyCode {
+        match self {
+            InputAction::SelectTower1 => KeyCode::Digit1,
+            InputAction::SelectTower2 => KeyCode::Digit2,
+            InputAction::SelectTower3 => KeyCode::Digit3,
+            InputAction::Pause => KeyCode::Space,
+            InputAction::CancelSelection => KeyCode::Escape,
+            InputAction::Upgrade => KeyCode::KeyU,
+            InputAction::Sell => KeyCode::KeyX, // S sert au défilement (WASD)
+            InputAction::SpeedUp => KeyCode::KeyF,
+            InputAction::FrameStep => KeyCode::Period,
+            InputAction::CallWave => KeyCode::KeyN,
+            InputAction::BackToMenu => KeyCode::KeyM,
+            InputAction::PanUp => KeyCode::KeyW,
+            InputAction::PanDown => KeyCode::KeyS,
+            InputAction::PanLeft => KeyCode::KeyA,
+            InputAction::PanRight => KeyCode::KeyD,
+        }
+    }
+}
+
+// Touches associées à chaque action (rechargées depuis controls.ron)
+#[derive(Resource, Clone)]
+pub struct InputMap {
+    bindings: HashMap<InputAction, KeyCode>,
+}
+
+// Format du fichier de config (versionné pour pouvoir évoluer)
+#[derive(Serialize, Deserialize)]
+struct InputConfigFile {
+    version: u32,
+    bindings: Vec<(InputAction, KeyCode)>,
+}
+
+// Version 2 : ajout du défilement (WASD), Vendre passe de S à X
+const INPUT_CONFIG_VERSION: u32 = 2;
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self { bindings: InputAction::ALL.iter().map(|a| (*a, a.default_key())).collect() }
+    }
+}
+
+impl InputMap {
+    fn path() -> PathBuf {
+        storage::config_dir().join("controls.ron")
+    }
+
+    // Les actions absentes du fichier gardent leur touche par défaut.
+    // Les anciennes versions sont migrées, une version inconnue est ignorée
+    pub fn load() -> Self {
+        let mut map = Self::default();
+        let Some(file) = storage::load_ron::<InputConfigFile>(&Self::path()) else { return map; };
+        let bindings: Vec<(InputAction, KeyCode)> = match file.version {
+            INPUT_CONFIG_VERSION => file.bindings,
+            // Version 1 : Vendre sur S était l'ancien défaut, il reprend le nouveau (S sert au défilement)
+            1 => file.bindings.into_iter().filter(|binding| *binding != (InputAction::Sell, KeyCode::KeyS)).collect(),
+            version => {
+                println!("controls.ron : version {version} non prise en charge, touches par défaut");
+                return map;
+            }
+        };
+        // Via bind : une touche du fichier déjà prise par défaut par une autre action lui est échangée, pas de doublon
+        for (action, key) in bindings {
+            map.bind(action, key);
+        }
+        map
+    }
+
+    pub fn save(&self) {
+        let file = InputConfigFile {
+            version: INPUT_CONFIG_VERSION,
+            bindings: InputAction::ALL.iter().map(|a| (*a, self.key(*a))).collect(),
+        };
+        storage::save_ron(&Self::path(), &file);
+    }
+
+    pub fn key(&self, action: InputAction) -> KeyCode {
+        self.bindings.get(&action).copied().unwrap_or(action.default_key())
+    }
+
+    // Associe une touche à une action ; l'action qui l'utilisait récupère l'ancienne touche
+    pub fn bind(&mut self, action: InputAction, key: KeyCode) {
+        let previous = self.key(action);
+        if let Some(other) = InputAction::ALL.iter().copied().find(|a| *a != action && self.key(*a) == key) {
+            self.bindings.insert(other, previous);
+        }
+        self.bindings.insert(action, key);
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+// Actions déclenchées cette frame (et maintenues), calculées une fois pour tout le monde
+#[derive(Resource, Default)]
+pub struct ActionState {
+    just_pressed: HashSet<InputAction>,
+    pressed: HashSet<InputAction>,
+}
+
+impl ActionState {
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.pressed.contains(&action)
+    }
+}
+
+pub struct InputMapPlugin;
+
+impl Plugin for InputMapPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(InputMap::load())
+            .init_resource::<ActionState>()
+            .add_systems(PreUpdate, update_action_state.after(InputSystem));
+    }
+}
+
+fn update_action_state(keys: Res<ButtonInput<KeyCode>>, map: Res<InputMap>, mut actions: ResMut<ActionState>) {
+    actions.just_pressed.clear();
+    actions.pressed.clear();
+    for action in InputAction::ALL {
+        if keys.just_pressed(map.key(action)) {
+            actions.just_pressed.insert(action);
+        }
+        if keys.pressed(map.key(action)) {
+            actions.pressed.insert(action);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+use bevy::math::Vec2;
+
 // Équivalent de PathPoint.java
 #[derive(Debug)]
 pub struct PathPoint {
@@ -7,6 +9,9 @@ pub struct PathPoint {
     pub y: usize,
 }
 
+// Identifiant du niveau (clé des meilleurs scores du profil)
+pub const LEVEL_NAME: &str = "level_1";
+
 // Le tableau de 20x20 de LevelBuild.java
 const LEVEL_DATA: [[u32; 20]; 20] = [
     [ 0, 0, 0, 0, 15, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1 ],
@@ -31,6 +36,35 @@ const LEVEL_DATA: [[u32; 20]; 20] = [
     [ 20, 2, 6, 8, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 11, 0, 0, 8, 14]
 ];
 
+// --- Géométrie de la carte dans le monde ---
+pub const TILE_SIZE: f32 = 32.0;
+pub const MAP_TILES: usize = 20;
+pub const MAP_WIDTH: f32 = MAP_TILES as f32 * TILE_SIZE;
+pub const MAP_HEIGHT: f32 = MAP_TILES as f32 * TILE_SIZE;
+// Hauteur de la barre du bas (HUD), en unités du monde à l'échelle 1
+pub const HUD_HEIGHT: f32 = 100.0;
+// Zone visible minimale : la carte et la barre du bas (la caméra s'adapte à la fenêtre)
+pub const VIEW_WIDTH: f32 = MAP_WIDTH;
+pub const VIEW_HEIGHT: f32 = MAP_HEIGHT + HUD_HEIGHT;
+// La carte est remontée de la moitié du HUD pour que l'ensemble reste centré
+const MAP_VERTICAL_SHIFT: f32 = HUD_HEIGHT / 2.0;
+
+// Centre de la case (x, y) dans le monde (y = 0 en haut de la carte)
+pub fn tile_to_world(x: usize, y: usize) -> Vec2 {
+    Vec2::new(
+        -MAP_WIDTH / 2.0 + TILE_SIZE / 2.0 + x as f32 * TILE_SIZE,
+        MAP_HEIGHT / 2.0 - TILE_SIZE / 2.0 + MAP_VERTICAL_SHIFT - y as f32 * TILE_SIZE,
+    )
+}
+
+// Case sous une position du monde, None en dehors de la carte
+pub fn world_to_tile(position: Vec2) -> Option<(usize, usize)> {
+    let x = ((position.x + MAP_WIDTH / 2.0) / TILE_SIZE).floor();
+    let y = ((MAP_HEIGHT / 2.0 + MAP_VERTICAL_SHIFT - position.y) / TILE_SIZE).floor();
+    let range = 0.0..MAP_TILES as f32;
+    (range.contains(&x) && range.contains(&y)).then_some((x as usize, y as usize))
+}
+
 pub fn get_level_data() -> &'static [[u32; 20]; 20] {
     &LEVEL_DATA
 }
@@ -0,0 +1,219 @@
+// src/loading.rs
+// Écran de chargement (AppState::Loading, état de départ) : attend les fichiers du jeu (image du menu,
+// atlas de sprites et son image, tables de langue) avec une barre de progression, puis passe au menu.
+// Si un fichier manque ou est invalide, un écran d'erreur liste les fichiers en cause.
+// Le niveau et les sons sont compilés dans le jeu : rien à attendre de ce côté
+
+use bevy::{prelude::*, app::AppExit, asset::LoadState};
+use crate::{
+    AppState, GameAssets,
+    atlas::{CurrentAtlas, SpriteAtlas},
+    i18n::Localization,
+    menu::{NORMAL_BUTTON, HOVERED_BUTTON, PRESSED_BUTTON},
+};
+
+#[derive(Component)]
+struct LoadingUI;
+
+#[derive(Component)]
+struct ProgressFill;
+
+#[derive(Component)]
+struct ProgressText;
+
+#[derive(Component)]
+enum LoadingErrorButton {
+    Continue,
+    Quit,
+}
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::Loading), setup_loading)
+            .add_systems(Update, (check_loading, loading_error_buttons).run_if(in_state(AppState::Loading)))
+            .add_systems(OnExit(AppState::Loading), cleanup_loading);
+    }
+}
+
+// Chemin et état de chargement d'un fichier
+fn status<A: Asset>(asset_server: &AssetServer, handle: &Handle<A>) -> (String, LoadState) {
+    let path = handle.path().map(|p| p.to_string()).unwrap_or_default();
+    (path, asset_server.get_load_state(handle.id()).unwrap_or(LoadState::NotLoaded))
+}
+
+// Tous les fichiers attendus par le jeu
+fn tracked_assets(
+    asset_server: &AssetServer,
+    game_assets: &GameAssets,
+    localization: &Localization,
+    current_atlas: &CurrentAtlas,
+    atlases: &Assets<SpriteAtlas>,
+    sprite_atlas: &SpriteAtlas,
+) -> Vec<(String, LoadState)> {
+    let mut assets = vec![status(asset_server, &game_assets.menu_background)];
+    assets.extend(localization.tables().map(|table| status(asset_server, table)));
+
+    // L'image de l'atlas n'est connue qu'une fois son descripteur lu
+    let descriptor = status(asset_server, &current_atlas.0);
+    let descriptor_failed = descriptor.1 == LoadState::Failed;
+    assets.push(descriptor);
+    if !descriptor_failed {
+        assets.push(match atlases.get(&current_atlas.0) {
+            Some(atlas) => {
+                let (path, state) = status(asset_server, &atlas.image);
+                // Prêt quand l'atlas utilisé par le jeu est bien celui-ci (remplacé par atlas.rs)
+                let in_use = sprite_atlas.image == atlas.image;
+                (path, if state == LoadState::Loaded && !in_use { LoadState::Loading } else { state })
+            }
+            None => (String::new(), LoadState::Loading),
+        });
+    }
+    assets
+}
+
+fn setup_loading(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.1, 0.1, 0.1).into(),
+            ..default()
+        },
+        LoadingUI,
+    )).with_children(|parent| {
+        // Barre de progression (pas de texte traduit : les langues sont elles-mêmes en cours de chargement)
+        parent.spawn(NodeBundle {
+            style: Style { width: Val::Px(300.0), height: Val::Px(20.0), border: UiRect::all(Val::Px(2.0)), ..default() },
+            background_color: Color::BLACK.into(), border_color: BorderColor(Color::WHITE), ..default()
+        }).with_children(|bar| {
+            bar.spawn((NodeBundle { style: Style { width: Val::Percent(0.0), height: Val::Percent(100.0), ..default() }, background_color: Color::rgb(0.35, 0.75, 0.35).into(), ..default() }, ProgressFill));
+        });
+        parent.spawn((TextBundle::from_section("0%", TextStyle { font_size: 20.0, color: Color::WHITE, ..default() })
+            .with_style(Style { margin: UiRect::top(Val::Px(8.0)), ..default() }), ProgressText));
+    });
+}
+
+fn check_loading(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    localization: Res<Localization>,
+    current_atlas: Res<CurrentAtlas>,
+    atlases: Res<Assets<SpriteAtlas>>,
+    sprite_atlas: Res<SpriteAtlas>,
+    mut fills: Query<&mut Style, With<ProgressFill>>,
+    mut texts: Query<&mut Text, With<ProgressText>>,
+    ui: Query<Entity, With<LoadingUI>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut shown_error: Local<bool>,
+) {
+    if *shown_error { return; }
+
+    let assets = tracked_assets(&asset_server, &game_assets, &localization, &current_atlas, &atlases, &sprite_atlas);
+    let done = assets.iter().filter(|(_, state)| matches!(state, LoadState::Loaded | LoadState::Failed)).count();
+    let progress = done as f32 / assets.len() as f32 * 100.0;
+    for mut style in fills.iter_mut() {
+        style.width = Val::Percent(progress);
+    }
+    for mut text in texts.iter_mut() {
+        text.sections[0].value = format!("{progress:.0}%");
+    }
+    if done < assets.len() { return; }
+
+    let failed: Vec<String> = assets.into_iter().filter(|(_, state)| *state == LoadState::Failed).map(|(path, _)| path).collect();
+    if failed.is_empty() {
+        next_state.set(AppState::Menu);
+        return;
+    }
+
+    // Remplace la barre par la liste des fichiers en erreur
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    spawn_loading_error(&mut commands, &localization, &failed);
+    *shown_error = true;
+}
+
+fn spawn_loading_error(commands: &mut Commands, loc: &Localization, failed: &[String]) {
+    // Les tables de langue peuvent faire partie des fichiers en erreur : texte anglais par défaut
+    let text = |key: &str, fallback: &str| -> String {
+        match loc.get(key) {
+            value if value == key => fallback.to_string(),
+            value => value.to_string(),
+        }
+    };
+    let text_style = TextStyle { font_size: 20.0, color: Color::WHITE, ..default() };
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.1, 0.1, 0.1).into(),
+            ..default()
+        },
+        LoadingUI,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(text("loading.failed", "Some files could not be loaded:"), TextStyle { font_size: 28.0, color: Color::rgb(0.9, 0.3, 0.3), ..default() })
+            .with_style(Style { margin: UiRect::bottom(Val::Px(16.0)), ..default() }));
+        for path in failed {
+            parent.spawn(TextBundle::from_section(format!("assets/{path}"), text_style.clone()));
+        }
+
+        parent.spawn(NodeBundle { style: Style { margin: UiRect::top(Val::Px(20.0)), ..default() }, ..default() })
+            .with_children(|row| {
+                for (label, action) in [(text("loading.continue", "CONTINUE"), LoadingErrorButton::Continue), (text("menu.quit", "QUIT"), LoadingErrorButton::Quit)] {
+                    row.spawn((
+                        ButtonBundle {
+                            style: Style { width: Val::Px(150.0), height: Val::Px(40.0), margin: UiRect::all(Val::Px(6.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, ..default() },
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        action,
+                    )).with_children(|b| {
+                        b.spawn(TextBundle::from_section(label, text_style.clone()));
+                    });
+                }
+            });
+    });
+}
+
+// Continuer sans les fichiers manquants (sprites ou textes absents), ou quitter
+fn loading_error_buttons(
+    mut query: Query<(&Interaction, &LoadingErrorButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut app_exit: EventWriter<AppExit>,
+) {
+    for (interaction, action, mut color) in query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                match action {
+                    LoadingErrorButton::Continue => next_state.set(AppState::Menu),
+                    LoadingErrorButton::Quit => { app_exit.send(AppExit); }
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+fn cleanup_loading(mut commands: Commands, query: Query<Entity, With<LoadingUI>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,15 +1,24 @@
-use bevy::{prelude::*, app::AppExit, asset::AssetServer, diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},};
+// Les systèmes Bevy ont naturellement beaucoup de paramètres et des requêtes aux types longs
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
+use bevy::{prelude::*, asset::AssetServer, diagnostic::FrameTimeDiagnosticsPlugin,};
 
 // enum de tous les états possibles de l'application (basé sur GameState.java)
 #[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
 pub enum AppState {
-    #[default] // L'état par défaut au lancement
+    #[default] // L'état par défaut au lancement : chargement des fichiers, puis menu
+    Loading,
     Menu,
     Playing,
     Simulation,
+    Controls,
+    Stats,
+    GameOver,
+    Settings,
 }
 
 
+pub mod core;
 pub mod menu;
 pub mod game;
 pub mod constants;
@@ -18,87 +27,135 @@ pub mod enemy;
 pub mod tower;
 pub mod projectile;
 pub mod simulation;
+pub mod animation;
+pub mod storage;
+pub mod input;
+pub mod controls;
+pub mod speed;
+pub mod wave;
+pub mod save;
+pub mod profile;
+pub mod stats;
+pub mod score;
+pub mod gameover;
+pub mod economy;
+pub mod difficulty;
+pub mod boss;
+pub mod effects;
+pub mod hud;
+pub mod camera;
+pub mod minimap;
+pub mod i18n;
+pub mod settings;
+pub mod sound;
+pub mod atlas;
+pub mod loading;
 
 use menu::MenuPlugin;
 use game::GamePlugin;
 use enemy::EnemyPlugin;
 use simulation::SimulationPlugin;
 use projectile::ProjectilePlugin;
+use animation::AnimationPlugin;
+use input::InputMapPlugin;
+use controls::ControlsPlugin;
+use speed::GameSpeedPlugin;
+use wave::WavePlugin;
+use save::SavePlugin;
+use profile::ProfilePlugin;
+use stats::StatsPlugin;
+use score::ScorePlugin;
+use gameover::GameOverPlugin;
+use economy::EconomyPlugin;
+use boss::BossPlugin;
+use effects::EffectsPlugin;
+use hud::HudPlugin;
+use camera::CameraPlugin;
+use minimap::MinimapPlugin;
+use i18n::I18nPlugin;
+use settings::SettingsPlugin;
+use sound::SoundPlugin;
+use atlas::AtlasPlugin;
+use loading::LoadingPlugin;
+use crate::core::{ProjectileHit, TowerFired, DamageEvent, EnemyKilled, EnemyLeaked, TowerBuilt, PurchaseDenied, WaveStarted, WaveCleared};
 
-// définition des ressources de jeu (images, atlas, etc)
+// définition des ressources de jeu (images hors atlas ; l'atlas de sprites est géré par AtlasPlugin)
 #[derive(Resource)]
 pub struct GameAssets {
     pub menu_background: Handle<Image>,
-    pub sprite_atlas: Handle<Image>,
-    pub sprite_atlas_layout: Handle<TextureAtlasLayout>,
 }
 
-#[derive(Resource, Default)]
-pub struct GlobalPause(pub bool);
-
 fn main() {
     App::new()
         .add_plugins((
             DefaultPlugins.set(WindowPlugin {
                 primary_window: Some(Window {
                     title: "Tower Defense (Bevy)".into(),
-                    resolution: (640.0, 740.0).into(), 
-                    resizable: false,
+                    resolution: (level::VIEW_WIDTH, level::VIEW_HEIGHT).into(),
+                    resizable: true,
                     ..default()
                 }),
                 ..default()
             }),
-            FrameTimeDiagnosticsPlugin::default(),
+            FrameTimeDiagnosticsPlugin,
         ))
         .init_state::<AppState>()
-        .init_resource::<GlobalPause>() 
+        // Événements de combat partagés entre Playing et Simulation
+        .add_event::<ProjectileHit>()
+        .add_event::<TowerFired>()
+        .add_event::<DamageEvent>()
+        .add_event::<EnemyKilled>()
+        .add_event::<EnemyLeaked>()
+        .add_event::<TowerBuilt>()
+        .add_event::<PurchaseDenied>()
+        .add_event::<WaveStarted>()
+        .add_event::<WaveCleared>()
         .add_plugins((
             MenuPlugin,
             GamePlugin,
-            // SettingsPlugin supprimé
             EnemyPlugin,
             ProjectilePlugin,
             SimulationPlugin,
+            AnimationPlugin,
+            InputMapPlugin,
+            ControlsPlugin,
+            GameSpeedPlugin,
+            WavePlugin,
+            SavePlugin,
+            ProfilePlugin,
+            StatsPlugin,
+            ScorePlugin,
+            GameOverPlugin,
+        ))
+        .add_plugins((
+            EconomyPlugin,
+            BossPlugin,
+            EffectsPlugin,
+            HudPlugin,
+            CameraPlugin,
+            MinimapPlugin,
+            I18nPlugin,
+            SettingsPlugin,
+            SoundPlugin,
+            AtlasPlugin,
+            LoadingPlugin,
         ))
-        .add_systems(Startup, (setup_camera, setup_assets))
+        .add_systems(Startup, setup_assets)
         .run();
 }
 
-/// Lancement de la caméra 2D dans le monde
-fn setup_camera(mut commands: Commands) {
-    commands.spawn(Camera2dBundle::default());
-}
-
 // Système de chargement des assets et création de la ressource GameAssets au démarrage
-fn setup_assets(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
-) {
+fn setup_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
     // chargement des images
     let menu_bg_handle = asset_server.load("menuimg.png");
-    let atlas_img_handle = asset_server.load("spriteatlas.png");
-
-    // définition du layout qui est le découpage de l'atlas
-    // l'atlas fait 10 sprites de large par 3 de haut
-    let atlas_layout = TextureAtlasLayout::from_grid(
-        Vec2::new(32.0, 32.0), // Taille de chaque sprite
-        10,                    // Nombre de colonnes
-        3,                     // Nombre de lignes
-        None,                  // Pas de padding
-        None,                  // Pas d'offset
-    );
-    let atlas_layout_handle = texture_atlas_layouts.add(atlas_layout);
 
     // Création de la ressource GameAssets
     let game_assets = GameAssets {
         menu_background: menu_bg_handle,
-        sprite_atlas: atlas_img_handle,
-        sprite_atlas_layout: atlas_layout_handle,
     };
 
     // Insertion dans le monde
     commands.insert_resource(game_assets);
 
     println!("Assets chargés et Ressource 'GameAssets' créée !");
-}
\ No newline at end of file
+}
@@ -3,6 +3,9 @@ use bevy::{
     app::AppExit};
 use crate::AppState; 
 use crate::GameAssets;
+use crate::save::{self, ContinueSession};
+use crate::difficulty::Difficulty;
+use crate::i18n::Localized;
 
 // Composant vide pour marquer les entitées créées par le menu pour les trouver et les supprimer facilement si besoin
 #[derive(Component)]
@@ -11,15 +14,20 @@ struct MenuUI;
 // Composant pour les boutons, avec les actions associées
 #[derive(Component)]
 enum MenuButtonAction {
+    Continue,
     Play,
+    Difficulty,
     Simulation,
+    Controls,
+    Stats,
+    Settings,
     Quit,
 }
 
 // Couleurs des boutons
-const NORMAL_BUTTON: Color = Color::rgb(0.15, 0.15, 0.15);
-const HOVERED_BUTTON: Color = Color::rgb(0.25, 0.25, 0.25);
-const PRESSED_BUTTON: Color = Color::rgb(0.35, 0.75, 0.35);
+pub const NORMAL_BUTTON: Color = Color::rgb(0.15, 0.15, 0.15);
+pub const HOVERED_BUTTON: Color = Color::rgb(0.25, 0.25, 0.25);
+pub const PRESSED_BUTTON: Color = Color::rgb(0.35, 0.75, 0.35);
 
 // équivalent de la classe "Menu.java"
 pub struct MenuPlugin;
@@ -29,6 +37,9 @@ impl Plugin for MenuPlugin {
         app
             // Ajouts des systemes qui s'éxécutent lors des changements d'état
             
+            // Difficulté de la prochaine partie, choisie dans le menu
+            .init_resource::<Difficulty>()
+
             // S'exécute 1x quand on *entre* dans AppState::Menu
             .add_systems(OnEnter(AppState::Menu), setup_menu)
             
@@ -44,7 +55,7 @@ impl Plugin for MenuPlugin {
 }
 
 // équivalent du constructeur Menu() ou initButtons() en java
-fn setup_menu(mut commands: Commands, assets: Res<GameAssets>) {
+fn setup_menu(mut commands: Commands, assets: Res<GameAssets>, difficulty: Res<Difficulty>) {
     println!("Bienvenue au Menu !");
     
     // Image du fond
@@ -95,6 +106,20 @@ fn setup_menu(mut commands: Commands, assets: Res<GameAssets>) {
             ..default() // police par défaut de Bevy
         };
 
+        // --- Bouton "CONTINUE" (seulement s'il existe une partie sauvegardée) ---
+        if save::has_save() {
+            parent.spawn((
+                ButtonBundle {
+                    style: button_style.clone(),
+                    background_color: NORMAL_BUTTON.into(),
+                    ..default()
+                },
+                MenuButtonAction::Continue,
+            ))
+            .with_children(|parent| {
+                parent.spawn((TextBundle::from_section("", text_style.clone()), Localized("menu.continue")));
+            });
+        }
         // --- Bouton "PLAY" ---
         parent.spawn((
             ButtonBundle {
@@ -105,7 +130,19 @@ fn setup_menu(mut commands: Commands, assets: Res<GameAssets>) {
             MenuButtonAction::Play, // Ajoute le composant d'action
         ))
         .with_children(|parent| {
-            parent.spawn(TextBundle::from_section("PLAY", text_style.clone()));
+            parent.spawn((TextBundle::from_section("", text_style.clone()), Localized("menu.play")));
+        });
+        // --- Bouton "DIFFICULTÉ" (change à chaque clic) ---
+        parent.spawn((
+            ButtonBundle {
+                style: button_style.clone(),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            MenuButtonAction::Difficulty,
+        ))
+        .with_children(|parent| {
+            parent.spawn((TextBundle::from_section("", text_style.clone()), Localized(difficulty.key())));
         });
         // --- Bouton "SIMULATION" ---
         parent.spawn((
@@ -117,7 +154,43 @@ fn setup_menu(mut commands: Commands, assets: Res<GameAssets>) {
             MenuButtonAction::Simulation,
         ))
         .with_children(|parent| {
-            parent.spawn(TextBundle::from_section("SIMULATION", text_style.clone()));
+            parent.spawn((TextBundle::from_section("", text_style.clone()), Localized("menu.simulation")));
+        });
+        // --- Bouton "CONTROLS" ---
+        parent.spawn((
+            ButtonBundle {
+                style: button_style.clone(),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            MenuButtonAction::Controls,
+        ))
+        .with_children(|parent| {
+            parent.spawn((TextBundle::from_section("", text_style.clone()), Localized("menu.controls")));
+        });
+        // --- Bouton "STATS" ---
+        parent.spawn((
+            ButtonBundle {
+                style: button_style.clone(),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            MenuButtonAction::Stats,
+        ))
+        .with_children(|parent| {
+            parent.spawn((TextBundle::from_section("", text_style.clone()), Localized("menu.stats")));
+        });
+        // --- Bouton "SETTINGS" ---
+        parent.spawn((
+            ButtonBundle {
+                style: button_style.clone(),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            MenuButtonAction::Settings,
+        ))
+        .with_children(|parent| {
+            parent.spawn((TextBundle::from_section("", text_style.clone()), Localized("menu.settings")));
         });
         // --- Bouton "QUIT" ---
         parent.spawn((
@@ -129,7 +202,7 @@ fn setup_menu(mut commands: Commands, assets: Res<GameAssets>) {
             MenuButtonAction::Quit,
         ))
         .with_children(|parent| {
-            parent.spawn(TextBundle::from_section("QUIT", text_style.clone()));
+            parent.spawn((TextBundle::from_section("", text_style.clone()), Localized("menu.quit")));
         });
     });
 }
@@ -145,6 +218,7 @@ fn cleanup_menu(mut commands: Commands, query: Query<Entity, With<MenuUI>>) {
 
 // systeme d'interaction avec les boutons
 fn button_interaction_system(
+    mut commands: Commands,
     mut interaction_query: Query<
         (Entity, &Interaction, &MenuButtonAction), // récupération de l'action
         (Changed<Interaction>, With<Button>),
@@ -152,6 +226,9 @@ fn button_interaction_system(
     mut next_state: ResMut<NextState<AppState>>, // Pour changer l'état
     mut app_exit_writer: EventWriter<AppExit>, // Pour quitter le jeu
     mut button_query: Query<&mut BackgroundColor, With<Button>>, // Pour changer la couleur
+    mut difficulty: ResMut<Difficulty>,
+    children_query: Query<&Children>,
+    mut labels: Query<&mut Localized>,
 ) {
     for (entity, interaction, action) in interaction_query.iter_mut() {
         
@@ -162,14 +239,36 @@ fn button_interaction_system(
                 *background_color = PRESSED_BUTTON.into();
                 // Exécute l'action associée au bouton
                 match action {
+                    MenuButtonAction::Continue => {
+                        // La sauvegarde est rechargée à l'entrée dans Playing (save.rs)
+                        commands.insert_resource(ContinueSession);
+                        next_state.set(AppState::Playing);
+                    }
                     MenuButtonAction::Play => {
                         println!("Bouton Play cliqué !");
                         next_state.set(AppState::Playing); // Change l'état
                     }
+                    MenuButtonAction::Difficulty => {
+                        *difficulty = difficulty.next();
+                        // Le libellé est le texte enfant du bouton
+                        if let Ok(children) = children_query.get(entity) {
+                            let mut iter = labels.iter_many_mut(children);
+                            while let Some(mut label) = iter.fetch_next() { label.0 = difficulty.key(); }
+                        }
+                    }
                     MenuButtonAction::Simulation => {
                         println!("Bouton Simulation cliqué !");
                         next_state.set(AppState::Simulation); // Change l'état
                     }
+                    MenuButtonAction::Controls => {
+                        next_state.set(AppState::Controls);
+                    }
+                    MenuButtonAction::Stats => {
+                        next_state.set(AppState::Stats);
+                    }
+                    MenuButtonAction::Settings => {
+                        next_state.set(AppState::Settings);
+                    }
                     MenuButtonAction::Quit => {
                         println!("Bouton Quit cliqué !");
                         app_exit_writer.send(AppExit); // Envoie l'événement pour quitter
@@ -0,0 +1,139 @@
+// src/minimap.rs
+// Minicarte en haut à droite : routes, ennemis et zone actuellement visible.
+// Affichée seulement quand le zoom cache une partie de la carte
+
+use bevy::{prelude::*, utils::HashSet, window::PrimaryWindow};
+use crate::{
+    AppState,
+    level::{self, MAP_WIDTH, MAP_HEIGHT, MAP_TILES, TILE_SIZE},
+    camera::{MainCamera, visible_size},
+    game::{GameComponent, TileType, get_tile_type},
+    enemy::Enemy,
+    boss::Boss,
+};
+
+const MINIMAP_SIZE: f32 = 100.0; // px
+const DOT_SIZE: f32 = 3.0;
+const BOSS_DOT_SIZE: f32 = 6.0;
+
+#[derive(Component)]
+struct Minimap;
+
+// Cadre de la zone visible
+#[derive(Component)]
+struct MinimapViewport;
+
+// Point d'un ennemi sur la minicarte
+#[derive(Component)]
+struct MinimapDot(Entity);
+
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::Playing), setup_minimap)
+            .add_systems(Update, (update_minimap_viewport, update_minimap_dots).run_if(in_state(AppState::Playing)));
+    }
+}
+
+// Position du monde -> fraction de la carte (0,0 en haut à gauche)
+fn map_fraction(position: Vec2) -> Vec2 {
+    let top_left = level::tile_to_world(0, 0) + Vec2::new(-TILE_SIZE, TILE_SIZE) / 2.0;
+    Vec2::new((position.x - top_left.x) / MAP_WIDTH, (top_left.y - position.y) / MAP_HEIGHT)
+}
+
+fn setup_minimap(mut commands: Commands) {
+    let tile = 100.0 / MAP_TILES as f32; // en %
+    commands.spawn((NodeBundle {
+        style: Style {
+            width: Val::Px(MINIMAP_SIZE), height: Val::Px(MINIMAP_SIZE), position_type: PositionType::Absolute,
+            top: Val::Px(8.0), right: Val::Px(8.0), border: UiRect::all(Val::Px(2.0)), overflow: Overflow::clip(), display: Display::None, ..default()
+        },
+        background_color: Color::rgb(0.3, 0.55, 0.25).into(), border_color: BorderColor(Color::BLACK), z_index: ZIndex::Global(45), ..default() },
+        // Interaction : un clic sur la minicarte ne pose pas de tour dessous
+        Interaction::default(), Minimap, GameComponent,
+    )).with_children(|parent| {
+        for (y, row) in level::get_level_data().iter().enumerate() {
+            for (x, &tile_id) in row.iter().enumerate() {
+                let color = match get_tile_type(tile_id) {
+                    TileType::Grass => continue,
+                    TileType::Water => Color::rgb(0.2, 0.45, 0.75),
+                    TileType::Road | TileType::Start | TileType::End => Color::rgb(0.75, 0.65, 0.45),
+                };
+                parent.spawn(NodeBundle {
+                    style: Style { width: Val::Percent(tile), height: Val::Percent(tile), position_type: PositionType::Absolute, left: Val::Percent(x as f32 * tile), top: Val::Percent(y as f32 * tile), ..default() },
+                    background_color: color.into(), ..default()
+                });
+            }
+        }
+        parent.spawn((NodeBundle {
+            style: Style { position_type: PositionType::Absolute, border: UiRect::all(Val::Px(1.0)), ..default() },
+            border_color: BorderColor(Color::WHITE), z_index: ZIndex::Local(2), ..default() }, MinimapViewport,
+        ));
+    });
+}
+
+fn update_minimap_viewport(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Transform, &OrthographicProjection), With<MainCamera>>,
+    mut minimap: Query<&mut Style, With<Minimap>>,
+    mut viewport: Query<&mut Style, (With<MinimapViewport>, Without<Minimap>)>,
+) {
+    let (Ok(window), Ok((transform, projection))) = (windows.get_single(), cameras.get_single()) else { return; };
+    let half = visible_size(window, projection) / 2.0;
+    let center = transform.translation.truncate();
+    // Coins de la vue, ramenés dans la carte
+    let min = map_fraction(center + Vec2::new(-half.x, half.y)).clamp(Vec2::ZERO, Vec2::ONE);
+    let max = map_fraction(center + Vec2::new(half.x, -half.y)).clamp(Vec2::ZERO, Vec2::ONE);
+
+    let whole_map = min == Vec2::ZERO && max == Vec2::ONE;
+    for mut style in minimap.iter_mut() {
+        let display = if whole_map { Display::None } else { Display::Flex };
+        if style.display != display { style.display = display; }
+    }
+    if whole_map { return; }
+    for mut style in viewport.iter_mut() {
+        style.left = Val::Percent(min.x * 100.0);
+        style.top = Val::Percent(min.y * 100.0);
+        style.width = Val::Percent((max.x - min.x) * 100.0);
+        style.height = Val::Percent((max.y - min.y) * 100.0);
+    }
+}
+
+// Un point par ennemi vivant, créé à son apparition et retiré à sa disparition
+fn update_minimap_dots(
+    mut commands: Commands,
+    minimap: Query<Entity, With<Minimap>>,
+    enemies: Query<(Entity, &Transform, Has<Boss>), With<Enemy>>,
+    mut dots: Query<(Entity, &MinimapDot, &mut Style)>,
+) {
+    let Ok(root) = minimap.get_single() else { return; };
+    let mut tracked = HashSet::new();
+    for (dot, MinimapDot(enemy), mut style) in dots.iter_mut() {
+        let Ok((_, transform, _)) = enemies.get(*enemy) else {
+            commands.entity(dot).despawn_recursive();
+            continue;
+        };
+        tracked.insert(*enemy);
+        let fraction = map_fraction(transform.translation.truncate());
+        style.left = Val::Percent(fraction.x * 100.0);
+        style.top = Val::Percent(fraction.y * 100.0);
+    }
+    for (enemy, transform, is_boss) in enemies.iter().filter(|(e, _, _)| !tracked.contains(e)) {
+        let size = if is_boss { BOSS_DOT_SIZE } else { DOT_SIZE };
+        let fraction = map_fraction(transform.translation.truncate());
+        commands.entity(root).with_children(|parent| {
+            parent.spawn((NodeBundle {
+                style: Style {
+                    width: Val::Px(size), height: Val::Px(size), position_type: PositionType::Absolute,
+                    left: Val::Percent(fraction.x * 100.0), top: Val::Percent(fraction.y * 100.0),
+                    // Centré sur la position de l'ennemi
+                    margin: UiRect { left: Val::Px(-size / 2.0), top: Val::Px(-size / 2.0), ..default() },
+                    ..default()
+                },
+                background_color: Color::RED.into(), z_index: ZIndex::Local(1), ..default() }, MinimapDot(enemy),
+            ));
+        });
+    }
+}
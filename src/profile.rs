@@ -0,0 +1,139 @@
+// src/profile.rs
+// Profil du joueur : statistiques conservées d'une partie à l'autre (profile.ron)
+// Seul le mode Playing est comptabilisé, pas la simulation
+
+use std::{collections::BTreeMap, path::PathBuf};
+use bevy::{prelude::*, app::AppExit};
+use serde::{Serialize, Deserialize};
+use crate::{
+    AppState,
+    storage,
+    level,
+    game::{PlayerStats, cleanup_game},
+    tower::TowerType,
+    wave::WaveManager,
+    score::Score,
+    core::{EnemyKilled, TowerBuilt},
+};
+
+const PROFILE_VERSION: u32 = 2;
+
+// Nombre de parties gardées dans le tableau des meilleurs scores
+pub const HIGH_SCORE_COUNT: usize = 10;
+
+// Meilleur résultat obtenu sur un niveau
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LevelBest {
+    pub score: u32,
+    pub waves_survived: u32,
+    pub lives: i32,
+    pub gold: i32,
+}
+
+impl LevelBest {
+    // Meilleur score d'abord, puis plus de vagues, de vies et d'or
+    fn is_better_than(&self, other: &LevelBest) -> bool {
+        (self.score, self.waves_survived, self.lives, self.gold) > (other.score, other.waves_survived, other.lives, other.gold)
+    }
+}
+
+// Une partie terminée (victoire ou défaite) dans le tableau des meilleurs scores
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighScore {
+    pub level: String,
+    pub score: u32,
+    pub waves: u32,
+    pub victory: bool,
+}
+
+// Les champs absents d'un ancien fichier prennent leur valeur par défaut
+#[derive(Resource, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerProfile {
+    version: u32,
+    pub best: BTreeMap<String, LevelBest>,      // Par niveau
+    pub kills: BTreeMap<u32, u64>,              // Par type d'ennemi
+    pub towers_built: BTreeMap<TowerType, u64>, // Par type de tour
+    pub play_time: f64,                         // En secondes
+    pub high_scores: Vec<HighScore>,            // Trié du meilleur au moins bon
+}
+
+impl PlayerProfile {
+    fn path() -> PathBuf {
+        storage::data_dir().join("profile.ron")
+    }
+
+    pub fn load() -> Self {
+        storage::load_ron(&Self::path()).unwrap_or_default()
+    }
+
+    pub fn save(&mut self) {
+        self.version = PROFILE_VERSION;
+        storage::save_ron(&Self::path(), self);
+    }
+
+    // Garde le résultat s'il bat le record du niveau
+    pub fn record_run(&mut self, level: &str, result: LevelBest) {
+        let best = self.best.entry(level.to_string()).or_default();
+        if result.is_better_than(best) {
+            *best = result;
+        }
+    }
+
+    // Ajoute une partie terminée ; renvoie son rang (0 = meilleur) si elle entre dans le tableau
+    pub fn record_high_score(&mut self, entry: HighScore) -> Option<usize> {
+        let rank = self.high_scores.iter().position(|h| entry.score > h.score).unwrap_or(self.high_scores.len());
+        if rank >= HIGH_SCORE_COUNT { return None; }
+        self.high_scores.insert(rank, entry);
+        self.high_scores.truncate(HIGH_SCORE_COUNT);
+        Some(rank)
+    }
+
+    pub fn total_kills(&self) -> u64 {
+        self.kills.values().sum()
+    }
+}
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(PlayerProfile::load())
+            .add_systems(Update, (count_kills, count_towers_built, count_play_time).run_if(in_state(AppState::Playing)))
+            .add_systems(OnExit(AppState::Playing), record_session.before(cleanup_game))
+            // Fermeture de la fenêtre en pleine partie
+            .add_systems(Last, record_session.run_if(in_state(AppState::Playing).and_then(on_event::<AppExit>())));
+    }
+}
+
+fn count_kills(mut events: EventReader<EnemyKilled>, mut profile: ResMut<PlayerProfile>) {
+    for event in events.read() {
+        *profile.kills.entry(event.enemy_type).or_default() += 1;
+    }
+}
+
+fn count_towers_built(mut events: EventReader<TowerBuilt>, mut profile: ResMut<PlayerProfile>) {
+    for event in events.read() {
+        *profile.towers_built.entry(event.tower_type).or_default() += 1;
+    }
+}
+
+// Temps réel passé en partie (pauses comprises)
+fn count_play_time(time: Res<Time<Real>>, mut profile: ResMut<PlayerProfile>) {
+    profile.play_time += time.delta_seconds_f64();
+}
+
+// Fin de session : record du niveau puis écriture du profil
+fn record_session(stats: Option<Res<PlayerStats>>, waves: Option<Res<WaveManager>>, score: Option<Res<Score>>, mut profile: ResMut<PlayerProfile>) {
+    if let (Some(stats), Some(waves), Some(score)) = (stats, waves, score) {
+        profile.record_run(level::LEVEL_NAME, LevelBest {
+            score: score.breakdown(&stats).total(),
+            waves_survived: waves.current as u32,
+            lives: stats.lives.max(0),
+            gold: stats.money,
+        });
+    }
+    profile.save();
+}
@@ -1,67 +1,224 @@
-use bevy::prelude::*;
-use crate::{AppState, enemy::Enemy, enemy::Health, GlobalPause};
+use bevy::{prelude::*, utils::HashMap};
+use crate::{
+    speed::gameplay_running,
+    core::{ProjectileHit, DamageEvent},
+    enemy::{Enemy, PendingDamage},
+    boss::Shield,
+    tower::TowerType,
+    constants::projectiles as ProjectileConstants,
+};
 
 // Composant Projectile
 #[derive(Component)]
 pub struct Projectile {
     pub target: Entity, // L'entité ennemie visée
+    pub source: Entity, // La tour qui a tiré
+    pub source_type: TowerType,
     pub damage: i32,
     pub speed: f32,
+    pub projectile_type: u32,
+    // Point visé : position prédite (balistique) ou dernière position connue de la cible (homing)
+    pub destination: Vec2,
 }
 
-pub struct ProjectilePlugin;
+// Projectile tombé au sol (raté ou cible morte), disparaît après un court instant
+#[derive(Component)]
+pub struct LandedProjectile {
+    pub timer: Timer,
+}
 
-fn not_paused(pause: Res<GlobalPause>) -> bool { !pause.0 }
+// Taille des cases de la grille de collision (une tuile)
+const COLLISION_CELL: f32 = 32.0;
+
+pub struct ProjectilePlugin;
 
 impl Plugin for ProjectilePlugin {
     fn build(&self, app: &mut App) {
         // On exécute si le jeu n'est pas en pause
-        app.add_systems(Update, move_projectiles.run_if(not_paused));
+        app.add_systems(Update, (move_projectiles, apply_projectile_hits, fade_landed_projectiles).chain().run_if(gameplay_running));
     }
 }
 
+// Calcule le point où le projectile rencontrera la cible si elle garde sa vitesse
+// (résolution de |P + V*t - S| = vitesse * t)
+pub fn predict_intercept(shooter: Vec2, target: Vec2, target_velocity: Vec2, speed: f32) -> Vec2 {
+    let to_target = target - shooter;
+    let a = target_velocity.length_squared() - speed * speed;
+    let b = 2.0 * to_target.dot(target_velocity);
+    let c = to_target.length_squared();
+
+    let t = if a.abs() < f32::EPSILON {
+        // Même vitesse : équation linéaire
+        if b.abs() < f32::EPSILON { 0.0 } else { -c / b }
+    } else {
+        let disc = b * b - 4.0 * a * c;
+        if disc < 0.0 { return target; } // Impossible à rattraper : on vise la position actuelle
+        let sqrt_disc = disc.sqrt();
+        let t1 = (-b - sqrt_disc) / (2.0 * a);
+        let t2 = (-b + sqrt_disc) / (2.0 * a);
+        match (t1 > 0.0, t2 > 0.0) {
+            (true, true) => t1.min(t2),
+            (true, false) => t1,
+            (false, true) => t2,
+            (false, false) => return target,
+        }
+    };
+
+    if t > 0.0 { target + target_velocity * t } else { target }
+}
+
+fn cell_of(pos: Vec2) -> (i32, i32) {
+    ((pos.x / COLLISION_CELL).floor() as i32, (pos.y / COLLISION_CELL).floor() as i32)
+}
+
+// Distance d'un point au segment [a, b]
+fn distance_to_segment(point: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq == 0.0 { return point.distance(a); }
+    let t = ((point - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    point.distance(a + ab * t)
+}
+
 fn move_projectiles(
     mut commands: Commands,
-    mut projectile_query: Query<(Entity, &mut Transform, &Projectile)>,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut Projectile)>,
     // On cherche n'importe quelle entité qui a une Transform (Ennemi normal ou Sim)
-    target_query: Query<&GlobalTransform>, 
+    target_query: Query<&GlobalTransform>,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<Projectile>)>,
+    mut pending_query: Query<&mut PendingDamage>,
     time: Res<Time>,
-    // On cherche n'importe quelle entité qui a de la vie
-    mut health_query: Query<&mut Health>,
+    mut hits: EventWriter<ProjectileHit>,
 ) {
-    for (proj_entity, mut proj_transform, projectile) in projectile_query.iter_mut() {
-        
-        // Si la cible existe toujours
-        if let Ok(target_transform) = target_query.get(projectile.target) {
-            
-            let target_pos = target_transform.translation().truncate();
-            let current_pos = proj_transform.translation.truncate();
-            let direction = target_pos - current_pos;
-            let distance = direction.length();
-            
-            let step = projectile.speed * time.delta_seconds();
-
-            if distance <= step {
-                // Application des dégâts
-                if let Ok(mut health) = health_query.get_mut(projectile.target) {
-                    health.current -= projectile.damage;
+    // Grille des ennemis construite seulement si un projectile balistique est en vol
+    let mut grid: Option<HashMap<(i32, i32), Vec<(Entity, Vec2)>>> = None;
+
+    for (proj_entity, mut proj_transform, mut projectile) in projectile_query.iter_mut() {
+        let homing = ProjectileConstants::is_homing(projectile.projectile_type);
+        let target_alive = target_query.get(projectile.target).ok();
+
+        // Un projectile guidé suit sa cible tant qu'elle vit
+        if homing && let Some(target_transform) = target_alive {
+            projectile.destination = target_transform.translation().truncate();
+        }
+
+        let current_pos = proj_transform.translation.truncate();
+        let direction = projectile.destination - current_pos;
+        let distance = direction.length();
+        let step = projectile.speed * time.delta_seconds();
+        let next_pos = if distance <= step { projectile.destination } else { current_pos + direction.normalize() * step };
+
+        // Cible touchée sur ce pas ?
+        let hit_target = if homing {
+            if distance <= step && target_alive.is_some() { Some(projectile.target) } else { None }
+        } else {
+            // Balistique : touche le premier ennemi traversé, pas forcément la cible
+            let grid = grid.get_or_insert_with(|| {
+                let mut cells: HashMap<(i32, i32), Vec<(Entity, Vec2)>> = HashMap::default();
+                for (e, t) in enemy_query.iter() {
+                    let p = t.translation.truncate();
+                    cells.entry(cell_of(p)).or_default().push((e, p));
+                }
+                cells
+            });
+            let radius = ProjectileConstants::get_hit_radius(projectile.projectile_type);
+            let (min_x, min_y) = cell_of(current_pos.min(next_pos) - Vec2::splat(radius));
+            let (max_x, max_y) = cell_of(current_pos.max(next_pos) + Vec2::splat(radius));
+            let mut best: Option<(Entity, f32)> = None;
+            for cx in min_x..=max_x {
+                for cy in min_y..=max_y {
+                    let Some(cell) = grid.get(&(cx, cy)) else { continue; };
+                    for &(e, p) in cell {
+                        if distance_to_segment(p, current_pos, next_pos) > radius { continue; }
+                        let along = p.distance_squared(current_pos);
+                        if best.is_none_or(|(_, d)| along < d) { best = Some((e, along)); }
+                    }
                 }
-                
-                // Détruire le projectile
-                commands.entity(proj_entity).despawn();
-            } else {
-                // Avancer
-                let movement = direction.normalize() * step;
-                proj_transform.translation.x += movement.x;
-                proj_transform.translation.y += movement.y;
-                
-                let angle = direction.y.atan2(direction.x);
-                proj_transform.rotation = Quat::from_rotation_z(angle);
             }
+            best.map(|(e, _)| e)
+        };
 
-        } else {
-            // Cible disparue/morte
+        if let Some(hit) = hit_target {
+            // Impact : les dégâts sont appliqués par le pipeline d'événements
+            hits.send(ProjectileHit {
+                projectile: proj_entity,
+                target: hit,
+                source: projectile.source,
+                source_type: projectile.source_type,
+                damage: projectile.damage,
+                projectile_type: projectile.projectile_type,
+                position: next_pos,
+            });
+            release_pending(&mut pending_query, &projectile);
             commands.entity(proj_entity).despawn();
+        } else if distance <= step {
+            // Arrivé au point visé sans rien toucher : le projectile tombe au sol
+            release_pending(&mut pending_query, &projectile);
+            proj_transform.translation = projectile.destination.extend(0.5);
+            commands.entity(proj_entity)
+                .remove::<Projectile>()
+                .insert(LandedProjectile { timer: Timer::from_seconds(1.0, TimerMode::Once) });
+        } else {
+            // Avancer
+            proj_transform.translation.x = next_pos.x;
+            proj_transform.translation.y = next_pos.y;
+
+            let angle = direction.y.atan2(direction.x);
+            proj_transform.rotation = Quat::from_rotation_z(angle);
         }
     }
-}
\ No newline at end of file
+}
+
+// Le projectile n'est plus "en vol" vers sa cible
+fn release_pending(pending_query: &mut Query<&mut PendingDamage>, projectile: &Projectile) {
+    if let Ok(mut pending) = pending_query.get_mut(projectile.target) {
+        pending.0 = (pending.0 - projectile.damage).max(0);
+    }
+}
+
+// Traduit chaque impact en dégâts, en gardant la tour comme source
+// Un bouclier actif (boss.rs) arrête le projectile
+pub fn apply_projectile_hits(mut hits: EventReader<ProjectileHit>, shields: Query<&Shield>, mut damages: EventWriter<DamageEvent>) {
+    for hit in hits.read() {
+        if shields.get(hit.target).is_ok_and(|s| s.active) { continue; }
+        damages.send(DamageEvent { target: hit.target, amount: hit.damage, source: Some(hit.source), source_type: Some(hit.source_type) });
+    }
+}
+
+fn fade_landed_projectiles(mut commands: Commands, time: Res<Time>, mut query: Query<(Entity, &mut LandedProjectile, &mut Sprite)>) {
+    for (entity, mut landed, mut sprite) in query.iter_mut() {
+        landed.timer.tick(time.delta());
+        sprite.color.set_a(1.0 - landed.timer.fraction());
+        if landed.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stationary_target_is_aimed_at_directly() {
+        let target = Vec2::new(120.0, -40.0);
+        assert_eq!(predict_intercept(Vec2::ZERO, target, Vec2::ZERO, 300.0), target);
+    }
+
+    #[test]
+    fn moving_target_is_met_at_the_intercept_point() {
+        let shooter = Vec2::ZERO;
+        let (target, velocity, speed) = (Vec2::new(200.0, 0.0), Vec2::new(0.0, 50.0), 300.0);
+        let aim = predict_intercept(shooter, target, velocity, speed);
+        // Le projectile et l'ennemi arrivent au point visé au même moment
+        let t = (aim - target).length() / velocity.length();
+        assert!(((aim - shooter).length() / speed - t).abs() < 1e-3);
+    }
+
+    #[test]
+    fn unreachable_target_is_aimed_at_its_current_position() {
+        // L'ennemi s'éloigne plus vite que le projectile
+        let target = Vec2::new(100.0, 0.0);
+        assert_eq!(predict_intercept(Vec2::ZERO, target, Vec2::new(400.0, 0.0), 300.0), target);
+    }
+}
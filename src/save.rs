@@ -0,0 +1,316 @@
+// src/save.rs
+// Sauvegarde de la partie en cours (AppState::Playing) dans un fichier RON versionné
+// Écrite en quittant la partie (retour au menu ou fermeture de la fenêtre), relue via "CONTINUE"
+
+use std::{fs, path::PathBuf, time::Duration};
+use bevy::{prelude::*, app::AppExit};
+use serde::{Serialize, Deserialize};
+use crate::{
+    AppState,
+    atlas::SpriteAtlas,
+    storage,
+    game::{PlayerStats, setup_game, cleanup_game, spawn_tower, spawn_projectile},
+    tower::{Tower, TowerType, TowerStats, SoldTowerStats, MAX_TIER},
+    enemy::{Enemy, Health, PathFollower, PendingDamage, base_speed, spawn_enemy},
+    projectile::Projectile,
+    wave::WaveManager,
+    score::Score,
+    economy::Ledger,
+    difficulty::Difficulty,
+    boss::{Boss, BossKind, HealAura, Shield, spawn_boss},
+    constants::enemies as EnemyConstants,
+};
+
+// À incrémenter à chaque changement du format
+// Les champs ajoutés depuis ont une valeur par défaut ; une sauvegarde plus récente est ignorée
+const SAVE_VERSION: u32 = 8;
+
+#[derive(Serialize, Deserialize)]
+struct SaveFile {
+    version: u32,
+    money: i32,
+    lives: i32,
+    #[serde(default)]
+    score: Score, // v2
+    #[serde(default)]
+    ledger: Ledger, // v3
+    #[serde(default)]
+    difficulty: Difficulty, // v4
+    #[serde(default)]
+    sold_towers: SoldTowerStats, // v7
+    wave: WaveSave,
+    towers: Vec<TowerSave>,
+    enemies: Vec<EnemySave>,
+    projectiles: Vec<ProjectileSave>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WaveSave {
+    current: usize,
+    spawned: usize,
+    in_break: bool,
+    spawn_elapsed: f32,
+    break_elapsed: f32,
+    #[serde(default)]
+    seed: Option<u64>, // v5
+}
+
+#[derive(Serialize, Deserialize)]
+struct TowerSave {
+    tower_type: TowerType,
+    tier: u32,
+    position: Vec2,
+    cooldown_elapsed: f32,
+    #[serde(default)]
+    stats: TowerStats, // v7
+}
+
+#[derive(Serialize, Deserialize)]
+struct EnemySave {
+    enemy_type: u32,
+    position: Vec2,
+    path_index: usize,
+    health: i32,
+    #[serde(default)]
+    max_health: Option<i32>, // v4
+    #[serde(default)]
+    boss: Option<BossKind>, // v6
+    #[serde(default)]
+    ability: Option<AbilitySave>, // v8
+}
+
+// Minuteurs des capacités de boss (les paliers de SpawnOnDamage se recalculent à partir de la vie)
+#[derive(Serialize, Deserialize)]
+enum AbilitySave {
+    HealAura { elapsed: f32 },
+    Shield { active: bool, duration_elapsed: f32, cooldown_elapsed: f32 },
+}
+
+// Les entités sont remplacées par leur indice dans les listes de tours / d'ennemis
+#[derive(Serialize, Deserialize)]
+struct ProjectileSave {
+    projectile_type: u32,
+    position: Vec2,
+    destination: Vec2,
+    damage: i32,
+    speed: f32,
+    target: Option<usize>,
+    source: Option<usize>,
+}
+
+// Demande de reprise de la sauvegarde à l'entrée dans Playing (posée par le menu)
+#[derive(Resource)]
+pub struct ContinueSession;
+
+fn save_path() -> PathBuf {
+    storage::data_dir().join("savegame.ron")
+}
+
+// Sauvegarde reprenable : lisible et d'une version connue
+// Un fichier invalide ou plus récent est mis de côté (savegame.ron.invalid) pour ne pas être écrasé
+fn read_save() -> Option<SaveFile> {
+    let path = save_path();
+    if !path.exists() { return None; }
+    let file = storage::load_ron::<SaveFile>(&path).filter(|file| {
+        if file.version > SAVE_VERSION {
+            println!("Sauvegarde en version {} (attendue : {}), ignorée.", file.version, SAVE_VERSION);
+        }
+        file.version <= SAVE_VERSION
+    });
+    if file.is_none() {
+        let invalid = path.with_extension("ron.invalid");
+        match fs::rename(&path, &invalid) {
+            Ok(()) => println!("Sauvegarde déplacée vers {}.", invalid.display()),
+            Err(e) => println!("Impossible de déplacer {} : {}", path.display(), e),
+        }
+    }
+    file
+}
+
+// Le bouton "CONTINUE" n'est affiché que pour une sauvegarde qui peut être reprise
+pub fn has_save() -> bool {
+    read_save().is_some()
+}
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::Playing), load_session.after(setup_game))
+            .add_systems(OnExit(AppState::Playing), save_session.before(cleanup_game))
+            // Fermeture de la fenêtre en pleine partie
+            .add_systems(Last, save_session.run_if(in_state(AppState::Playing).and_then(on_event::<AppExit>())));
+    }
+}
+
+fn save_session(
+    stats: Option<Res<PlayerStats>>,
+    waves: Option<Res<WaveManager>>,
+    score: Option<Res<Score>>,
+    ledger: Option<Res<Ledger>>,
+    sold_towers: Option<Res<SoldTowerStats>>,
+    difficulty: Res<Difficulty>,
+    towers: Query<(Entity, &Transform, &Tower, &TowerType, &TowerStats)>,
+    enemies: Query<(Entity, &Transform, &Enemy, &Health, &PathFollower, Option<&Boss>, Option<&HealAura>, Option<&Shield>)>,
+    projectiles: Query<(&Transform, &Projectile)>,
+) {
+    let (Some(stats), Some(waves)) = (stats, waves) else { return; };
+
+    // Partie perdue ou terminée : rien à reprendre
+    if stats.lives <= 0 || waves.is_finished() {
+        storage::remove(&save_path());
+        return;
+    }
+
+    let tower_entities: Vec<Entity> = towers.iter().map(|(e, ..)| e).collect();
+    let enemy_entities: Vec<Entity> = enemies.iter().map(|(e, ..)| e).collect();
+
+    let file = SaveFile {
+        version: SAVE_VERSION,
+        money: stats.money,
+        lives: stats.lives,
+        score: score.map(|s| *s).unwrap_or_default(),
+        ledger: ledger.map(|l| l.clone()).unwrap_or_default(),
+        difficulty: *difficulty,
+        sold_towers: sold_towers.map(|s| s.clone()).unwrap_or_default(),
+        wave: WaveSave {
+            current: waves.current,
+            spawned: waves.spawned,
+            in_break: waves.in_break,
+            spawn_elapsed: waves.spawn_timer.elapsed_secs(),
+            break_elapsed: waves.break_timer.elapsed_secs(),
+            seed: waves.seed,
+        },
+        towers: towers.iter().map(|(_, transform, tower, tower_type, stats)| TowerSave {
+            tower_type: *tower_type,
+            tier: tower.tier,
+            position: transform.translation.truncate(),
+            cooldown_elapsed: tower.cooldown.elapsed_secs(),
+            stats: *stats,
+        }).collect(),
+        enemies: enemies.iter().map(|(_, transform, enemy, health, follower, boss, aura, shield)| EnemySave {
+            enemy_type: enemy.enemy_type,
+            position: transform.translation.truncate(),
+            path_index: follower.path_index,
+            health: health.current,
+            max_health: Some(health.max),
+            boss: boss.map(|b| b.kind),
+            ability: match (aura, shield) {
+                (Some(aura), _) => Some(AbilitySave::HealAura { elapsed: aura.timer.elapsed_secs() }),
+                (_, Some(shield)) => Some(AbilitySave::Shield {
+                    active: shield.active,
+                    duration_elapsed: shield.duration.elapsed_secs(),
+                    cooldown_elapsed: shield.cooldown.elapsed_secs(),
+                }),
+                _ => None,
+            },
+        }).collect(),
+        projectiles: projectiles.iter().map(|(transform, projectile)| ProjectileSave {
+            projectile_type: projectile.projectile_type,
+            position: transform.translation.truncate(),
+            destination: projectile.destination,
+            damage: projectile.damage,
+            speed: projectile.speed,
+            target: enemy_entities.iter().position(|e| *e == projectile.target),
+            source: tower_entities.iter().position(|e| *e == projectile.source),
+        }).collect(),
+    };
+    storage::save_ron(&save_path(), &file);
+    println!("Partie sauvegardée (vague {}).", waves.wave_number());
+}
+
+// Remplace la partie neuve créée par setup_game par celle de la sauvegarde
+fn load_session(mut commands: Commands, atlas: Res<SpriteAtlas>, request: Option<Res<ContinueSession>>) {
+    if request.is_none() { return; }
+    commands.remove_resource::<ContinueSession>();
+
+    let Some(file) = read_save() else { return; };
+
+    commands.insert_resource(PlayerStats { money: file.money, lives: file.lives });
+    commands.insert_resource(file.score);
+    commands.insert_resource(file.ledger);
+    commands.insert_resource(file.difficulty);
+    commands.insert_resource(file.sold_towers);
+
+    let mut waves = match file.wave.seed {
+        Some(seed) => WaveManager::endless(seed),
+        None => WaveManager::default(),
+    };
+    waves.current = waves.wave_count().map_or(file.wave.current, |count| file.wave.current.min(count));
+    waves.spawned = file.wave.spawned;
+    waves.in_break = file.wave.in_break;
+    waves.spawn_timer.set_elapsed(Duration::from_secs_f32(file.wave.spawn_elapsed));
+    waves.break_timer.set_elapsed(Duration::from_secs_f32(file.wave.break_elapsed));
+    let wave_number = waves.wave_number();
+    commands.insert_resource(waves);
+
+    let towers: Vec<Entity> = file.towers.iter().map(|t| {
+        let mut tower = Tower::new(t.tower_type, t.tier.clamp(1, MAX_TIER));
+        tower.cooldown.set_elapsed(Duration::from_secs_f32(t.cooldown_elapsed));
+        let entity = spawn_tower(&mut commands, &atlas, t.tower_type, tower, t.position);
+        commands.entity(entity).insert(t.stats);
+        entity
+    }).collect();
+
+    let enemies: Vec<Entity> = file.enemies.iter()
+        .map(|e| match e.boss {
+            Some(kind) => {
+                let max = e.max_health.unwrap_or_else(|| file.difficulty.enemy_health(kind.base_health()));
+                let entity = spawn_boss(&mut commands, &atlas, kind, e.position, e.path_index, Health { current: e.health, max }, file.difficulty.enemy_speed(kind.base_speed()));
+                if let Some(ability) = &e.ability {
+                    restore_ability(&mut commands, entity, ability);
+                }
+                entity
+            }
+            None => {
+                let max = e.max_health.unwrap_or_else(|| file.difficulty.enemy_health(EnemyConstants::get_starthealth(e.enemy_type)));
+                let speed = file.difficulty.enemy_speed(base_speed(e.enemy_type));
+                spawn_enemy(&mut commands, &atlas, e.enemy_type, e.position, e.path_index, Health { current: e.health, max }, speed)
+            }
+        })
+        .collect();
+
+    // Les dégâts en vol sont recalculés à partir des projectiles rechargés
+    let mut pending = vec![0; enemies.len()];
+    for p in &file.projectiles {
+        let target = p.target.and_then(|i| enemies.get(i).copied());
+        if let Some(i) = p.target && target.is_some() { pending[i] += p.damage; }
+        spawn_projectile(&mut commands, &atlas, p.position, Projectile {
+            // Cible morte / tour vendue avant la sauvegarde : entité invalide, comme une cible disparue en jeu
+            target: target.unwrap_or(Entity::PLACEHOLDER),
+            source: p.source.and_then(|i| towers.get(i).copied()).unwrap_or(Entity::PLACEHOLDER),
+            // Chaque type de projectile n'est tiré que par un type de tour
+            source_type: [TowerType::Canon, TowerType::Archer, TowerType::Wizard].into_iter()
+                .find(|t| t.get_projectile_type() == p.projectile_type)
+                .unwrap_or(TowerType::Canon),
+            damage: p.damage,
+            speed: p.speed,
+            projectile_type: p.projectile_type,
+            destination: p.destination,
+        });
+    }
+    for (entity, amount) in enemies.iter().zip(pending) {
+        if amount > 0 { commands.entity(*entity).insert(PendingDamage(amount)); }
+    }
+
+    println!("Partie reprise : vague {}, {} tours, {} ennemis.", wave_number, towers.len(), enemies.len());
+}
+
+// Reprend les minuteurs d'une capacité une fois le boss créé (ses réglages restent ceux de spawn_boss)
+fn restore_ability(commands: &mut Commands, entity: Entity, ability: &AbilitySave) {
+    match *ability {
+        AbilitySave::HealAura { elapsed } => commands.add(move |world: &mut World| {
+            if let Some(mut aura) = world.get_mut::<HealAura>(entity) {
+                aura.timer.set_elapsed(Duration::from_secs_f32(elapsed));
+            }
+        }),
+        AbilitySave::Shield { active, duration_elapsed, cooldown_elapsed } => commands.add(move |world: &mut World| {
+            if let Some(mut shield) = world.get_mut::<Shield>(entity) {
+                shield.active = active;
+                shield.duration.set_elapsed(Duration::from_secs_f32(duration_elapsed));
+                shield.cooldown.set_elapsed(Duration::from_secs_f32(cooldown_elapsed));
+            }
+        }),
+    }
+}
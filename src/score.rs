@@ -0,0 +1,78 @@
+// src/score.rs
+// Score d'une partie : les points des kills, vagues et appels anticipés s'accumulent en jeu,
+// les vies et l'or restants sont ajoutés au calcul du total
+
+use bevy::prelude::*;
+use serde::{Serialize, Deserialize};
+use crate::{
+    AppState,
+    game::PlayerStats,
+    wave::update_waves,
+    core::{EnemyKilled, WaveStarted, WaveCleared},
+    constants::enemies as EnemyConstants,
+};
+
+const WAVE_POINTS: u32 = 100;       // Multiplié par le numéro de la vague
+const EARLY_POINTS_PER_SECOND: f32 = 10.0;
+const LIFE_POINTS: u32 = 50;
+const GOLD_POINTS_DIVISOR: u32 = 2; // 1 point pour 2 pièces d'or non dépensées
+
+#[derive(Resource, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Score {
+    pub kills: u32,
+    pub waves: u32,
+    pub early_calls: u32,
+}
+
+// Détail du score (HUD et écran de fin)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScoreBreakdown {
+    pub kills: u32,
+    pub waves: u32,
+    pub early_calls: u32,
+    pub lives: u32,
+    pub gold: u32,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> u32 {
+        self.kills + self.waves + self.early_calls + self.lives + self.gold
+    }
+}
+
+impl Score {
+    pub fn breakdown(&self, stats: &PlayerStats) -> ScoreBreakdown {
+        ScoreBreakdown {
+            kills: self.kills,
+            waves: self.waves,
+            early_calls: self.early_calls,
+            lives: stats.lives.max(0) as u32 * LIFE_POINTS,
+            gold: stats.money.max(0) as u32 / GOLD_POINTS_DIVISOR,
+        }
+    }
+}
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        // Le Score est remis à zéro par setup_game (et remplacé au chargement d'une partie)
+        // Après update_waves : la dernière vague est comptée avant le passage à GameOver (PostUpdate)
+        app.add_systems(Update, (score_kills, score_waves.after(update_waves)).run_if(in_state(AppState::Playing)));
+    }
+}
+
+fn score_kills(mut events: EventReader<EnemyKilled>, mut score: ResMut<Score>) {
+    for event in events.read() {
+        score.kills += EnemyConstants::get_score(event.enemy_type);
+    }
+}
+
+fn score_waves(mut started: EventReader<WaveStarted>, mut cleared: EventReader<WaveCleared>, mut score: ResMut<Score>) {
+    for event in started.read() {
+        score.early_calls += (event.early_seconds * EARLY_POINTS_PER_SECOND).round() as u32;
+    }
+    for event in cleared.read() {
+        score.waves += WAVE_POINTS * (event.wave as u32 + 1);
+    }
+}
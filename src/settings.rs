@@ -0,0 +1,386 @@
+// src/settings.rs
+// Options du joueur (settings.ron), chargées au lancement et appliquées dès qu'elles changent,
+// et leur écran (AppState::Settings), accessible depuis le menu
+
+use std::path::PathBuf;
+use bevy::{
+    prelude::*,
+    diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
+    window::{PrimaryWindow, WindowMode},
+};
+use serde::{Serialize, Deserialize};
+use crate::{
+    AppState, storage,
+    i18n::{Language, Localization, Localized},
+    atlas::AtlasTheme,
+    economy::EconomyRules,
+    speed::SpeedSetting,
+    menu::{NORMAL_BUTTON, HOVERED_BUTTON, PRESSED_BUTTON},
+};
+
+const VOLUME_STEP: u32 = 10; // %
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WindowModeSetting {
+    #[default]
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    fn next(&self) -> Self {
+        match self {
+            WindowModeSetting::Windowed => WindowModeSetting::Borderless,
+            WindowModeSetting::Borderless => WindowModeSetting::Fullscreen,
+            WindowModeSetting::Fullscreen => WindowModeSetting::Windowed,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            WindowModeSetting::Windowed => "settings.window.windowed",
+            WindowModeSetting::Borderless => "settings.window.borderless",
+            WindowModeSetting::Fullscreen => "settings.window.fullscreen",
+        }
+    }
+
+    fn mode(&self) -> WindowMode {
+        match self {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::Borderless => WindowMode::BorderlessFullscreen,
+            WindowModeSetting::Fullscreen => WindowMode::Fullscreen,
+        }
+    }
+}
+
+// Barres de vie des ennemis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HealthBarPolicy {
+    Always,
+    #[default]
+    Damaged, // Seulement quand l'ennemi est blessé
+    Never,
+}
+
+impl HealthBarPolicy {
+    fn next(&self) -> Self {
+        match self {
+            HealthBarPolicy::Always => HealthBarPolicy::Damaged,
+            HealthBarPolicy::Damaged => HealthBarPolicy::Never,
+            HealthBarPolicy::Never => HealthBarPolicy::Always,
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            HealthBarPolicy::Always => "settings.health.always",
+            HealthBarPolicy::Damaged => "settings.health.damaged",
+            HealthBarPolicy::Never => "settings.health.never",
+        }
+    }
+
+    pub fn shows(&self, current: i32, max: i32) -> bool {
+        match self {
+            HealthBarPolicy::Always => true,
+            HealthBarPolicy::Damaged => current < max,
+            HealthBarPolicy::Never => false,
+        }
+    }
+}
+
+// Les champs absents du fichier (ancienne version) gardent leur valeur par défaut
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub volume: u32, // % (général)
+    pub music_volume: u32, // %
+    pub effects_volume: u32, // %
+    pub language: Language,
+    pub window_mode: WindowModeSetting,
+    pub show_fps: bool,
+    pub health_bars: HealthBarPolicy,
+    pub default_speed: SpeedSetting,
+    pub sprites: AtlasTheme,
+    pub interest: bool, // Intérêts sur l'or gardé entre les vagues (EconomyRules)
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            volume: 80,
+            music_volume: 60,
+            effects_volume: 100,
+            language: Language::default(),
+            window_mode: WindowModeSetting::default(),
+            show_fps: false,
+            health_bars: HealthBarPolicy::default(),
+            default_speed: SpeedSetting::default(),
+            sprites: AtlasTheme::default(),
+            interest: true,
+        }
+    }
+}
+
+// Format du fichier de config (versionné pour pouvoir évoluer)
+#[derive(Serialize, Deserialize)]
+struct SettingsFile {
+    version: u32,
+    settings: Settings,
+}
+
+const SETTINGS_VERSION: u32 = 1;
+
+impl Settings {
+    fn path() -> PathBuf {
+        storage::config_dir().join("settings.ron")
+    }
+
+    pub fn load() -> Self {
+        storage::load_ron::<SettingsFile>(&Self::path()).map(|file| file.settings).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        storage::save_ron(&Self::path(), &SettingsFile { version: SETTINGS_VERSION, settings: self.clone() });
+    }
+}
+
+// Une ligne de l'écran des options
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum SettingKind {
+    Volume,
+    MusicVolume,
+    EffectsVolume,
+    Language,
+    WindowMode,
+    ShowFps,
+    HealthBars,
+    DefaultSpeed,
+    Sprites,
+    Interest,
+}
+
+impl SettingKind {
+    const ALL: [SettingKind; 10] = [
+        SettingKind::Volume,
+        SettingKind::MusicVolume,
+        SettingKind::EffectsVolume,
+        SettingKind::Language,
+        SettingKind::WindowMode,
+        SettingKind::ShowFps,
+        SettingKind::HealthBars,
+        SettingKind::DefaultSpeed,
+        SettingKind::Sprites,
+        SettingKind::Interest,
+    ];
+
+    fn key(&self) -> &'static str {
+        match self {
+            SettingKind::Volume => "settings.volume",
+            SettingKind::MusicVolume => "settings.music_volume",
+            SettingKind::EffectsVolume => "settings.effects_volume",
+            SettingKind::Language => "settings.language",
+            SettingKind::WindowMode => "settings.window_mode",
+            SettingKind::ShowFps => "settings.show_fps",
+            SettingKind::HealthBars => "settings.health_bars",
+            SettingKind::DefaultSpeed => "settings.default_speed",
+            SettingKind::Sprites => "settings.sprites",
+            SettingKind::Interest => "settings.interest",
+        }
+    }
+
+    // Passe à la valeur suivante (les volumes rebouclent à 0 après 100 %)
+    fn cycle(&self, settings: &mut Settings) {
+        let next_volume = |volume: u32| if volume >= 100 { 0 } else { volume + VOLUME_STEP };
+        match self {
+            SettingKind::Volume => settings.volume = next_volume(settings.volume),
+            SettingKind::MusicVolume => settings.music_volume = next_volume(settings.music_volume),
+            SettingKind::EffectsVolume => settings.effects_volume = next_volume(settings.effects_volume),
+            SettingKind::Language => settings.language = settings.language.next(),
+            SettingKind::WindowMode => settings.window_mode = settings.window_mode.next(),
+            SettingKind::ShowFps => settings.show_fps = !settings.show_fps,
+            SettingKind::HealthBars => settings.health_bars = settings.health_bars.next(),
+            SettingKind::DefaultSpeed => settings.default_speed = settings.default_speed.next(),
+            SettingKind::Sprites => settings.sprites = settings.sprites.next(),
+            SettingKind::Interest => settings.interest = !settings.interest,
+        }
+    }
+
+    fn value(&self, settings: &Settings, loc: &Localization) -> String {
+        match self {
+            SettingKind::Volume => format!("{}%", settings.volume),
+            SettingKind::MusicVolume => format!("{}%", settings.music_volume),
+            SettingKind::EffectsVolume => format!("{}%", settings.effects_volume),
+            // Nom de la langue, jamais traduit
+            SettingKind::Language => settings.language.native_name().to_string(),
+            SettingKind::WindowMode => loc.get(settings.window_mode.key()).to_string(),
+            SettingKind::ShowFps => loc.get(if settings.show_fps { "settings.on" } else { "settings.off" }).to_string(),
+            SettingKind::HealthBars => loc.get(settings.health_bars.key()).to_string(),
+            SettingKind::DefaultSpeed => settings.default_speed.label().to_string(),
+            SettingKind::Sprites => loc.get(settings.sprites.key()).to_string(),
+            SettingKind::Interest => loc.get(if settings.interest { "settings.on" } else { "settings.off" }).to_string(),
+        }
+    }
+}
+
+#[derive(Component)]
+struct SettingsUI;
+
+#[derive(Component)]
+struct BackButton;
+
+// Texte de la valeur d'une option
+#[derive(Component)]
+struct SettingValue(SettingKind);
+
+// Compteur d'images par seconde (option "show FPS"), présent dans tous les écrans
+#[derive(Component)]
+struct FpsCounter;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        let settings = Settings::load();
+        app
+            .insert_resource(settings.language)
+            .insert_resource(settings.sprites)
+            .insert_resource(settings)
+            .add_systems(Startup, setup_fps_counter)
+            .add_systems(Update, (apply_settings, update_fps_counter))
+            .add_systems(OnEnter(AppState::Settings), setup_settings)
+            .add_systems(Update, (settings_button_interaction, update_setting_values).chain().run_if(in_state(AppState::Settings)))
+            .add_systems(OnExit(AppState::Settings), cleanup_settings);
+    }
+}
+
+// Applique les options dès qu'elles changent (et au lancement)
+fn apply_settings(settings: Res<Settings>, mut language: ResMut<Language>, mut atlas_theme: ResMut<AtlasTheme>, mut economy: ResMut<EconomyRules>, mut windows: Query<&mut Window, With<PrimaryWindow>>, mut fps: Query<&mut Style, With<FpsCounter>>) {
+    if !settings.is_changed() { return; }
+    if *language != settings.language { *language = settings.language; }
+    if *atlas_theme != settings.sprites { *atlas_theme = settings.sprites; }
+    if economy.interest != settings.interest { economy.interest = settings.interest; }
+    for mut window in windows.iter_mut() {
+        let mode = settings.window_mode.mode();
+        if window.mode != mode { window.mode = mode; }
+    }
+    for mut style in fps.iter_mut() {
+        style.display = if settings.show_fps { Display::Flex } else { Display::None };
+    }
+}
+
+fn setup_fps_counter(mut commands: Commands, settings: Res<Settings>) {
+    commands.spawn((
+        TextBundle {
+            z_index: ZIndex::Global(200),
+            ..TextBundle::from_section("", TextStyle { font_size: 14.0, color: Color::YELLOW, ..default() })
+                .with_style(Style { position_type: PositionType::Absolute, top: Val::Px(4.0), left: Val::Px(4.0), display: if settings.show_fps { Display::Flex } else { Display::None }, ..default() })
+        },
+        FpsCounter,
+    ));
+}
+
+fn update_fps_counter(settings: Res<Settings>, diagnostics: Res<DiagnosticsStore>, mut texts: Query<&mut Text, With<FpsCounter>>) {
+    if !settings.show_fps { return; }
+    let fps = diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS).and_then(|d| d.smoothed()).unwrap_or(0.0);
+    for mut text in texts.iter_mut() {
+        text.sections[0].value = format!("FPS {:.0}", fps);
+    }
+}
+
+fn setup_settings(mut commands: Commands, settings: Res<Settings>, loc: Res<Localization>) {
+    let text_style = TextStyle { font_size: 20.0, color: Color::WHITE, ..default() };
+    let button_style = Style {
+        width: Val::Px(150.0),
+        height: Val::Px(36.0),
+        margin: UiRect::all(Val::Px(4.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.1, 0.1, 0.1).into(),
+            ..default()
+        },
+        SettingsUI,
+    )).with_children(|parent| {
+        parent.spawn((TextBundle::from_section("", TextStyle { font_size: 36.0, ..text_style.clone() })
+            .with_style(Style { margin: UiRect::bottom(Val::Px(20.0)), ..default() }), Localized("settings.title")));
+
+        // Une ligne par option : libellé + bouton qui fait défiler les valeurs
+        for kind in SettingKind::ALL {
+            parent.spawn(NodeBundle {
+                style: Style { width: Val::Px(380.0), justify_content: JustifyContent::SpaceBetween, align_items: AlignItems::Center, ..default() },
+                ..default()
+            }).with_children(|row| {
+                row.spawn((TextBundle::from_section("", text_style.clone()), Localized(kind.key())));
+                row.spawn((
+                    ButtonBundle { style: button_style.clone(), background_color: NORMAL_BUTTON.into(), ..default() },
+                    kind,
+                )).with_children(|b| {
+                    b.spawn((TextBundle::from_section(kind.value(&settings, &loc), text_style.clone()), SettingValue(kind)));
+                });
+            });
+        }
+
+        parent.spawn((
+            ButtonBundle { style: Style { margin: UiRect::top(Val::Px(20.0)), ..button_style.clone() }, background_color: NORMAL_BUTTON.into(), ..default() },
+            BackButton,
+        )).with_children(|b| {
+            b.spawn((TextBundle::from_section("", text_style.clone()), Localized("settings.back")));
+        });
+    });
+}
+
+fn settings_button_interaction(
+    mut rows: Query<(&Interaction, &SettingKind, &mut BackgroundColor), Changed<Interaction>>,
+    mut back: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<BackButton>, Without<SettingKind>)>,
+    mut settings: ResMut<Settings>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, kind, mut color) in rows.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                kind.cycle(&mut settings);
+                settings.save();
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+    for (interaction, mut color) in back.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                next_state.set(AppState::Menu);
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+// Valeurs affichées : les options, ou la langue (libellés traduits), ont changé
+fn update_setting_values(settings: Res<Settings>, loc: Res<Localization>, mut texts: Query<(&mut Text, &SettingValue)>) {
+    if !settings.is_changed() && !loc.is_changed() { return; }
+    for (mut text, value) in texts.iter_mut() {
+        text.sections[0].value = value.0.value(&settings, &loc);
+    }
+}
+
+fn cleanup_settings(mut commands: Commands, query: Query<Entity, With<SettingsUI>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
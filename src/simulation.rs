@@ -1,18 +1,26 @@
 // src/simulation.rs
 
+use std::time::Instant;
 use bevy::{
     prelude::*, 
+    ecs::query::BatchingStrategy,
     sprite::SpriteSheetBundle,
     diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
-    ui::node_bundles::AtlasImageBundle,
 };
 use crate::{
-    AppState, GameAssets, GlobalPause,
+    AppState,
+    atlas::SpriteAtlas,
     level, 
-    game::{Path, GameTile, TileType, get_tile_type, get_atlas_index, tower_shooting},
-    tower::{Tower, TowerType},
-    enemy::{Enemy, Health, HealthBar},
-    projectile::Projectile,
+    game::{Path, TileType, get_tile_type, tile_sprites, tower_shooting},
+    tower::{Tower, TowerType, MAX_TIER},
+    enemy::{Enemy, Health, HealthBar, HealthBarBackground, PendingDamage, Velocity, step_along_path, ENEMY_BATCH_SIZE},
+    projectile::{Projectile, LandedProjectile},
+    animation::{SpriteAnimator, clips},
+    input::{ActionState, InputAction},
+    constants::enemies as EnemyConstants,
+    speed::{GameSpeed, SpeedSetting, gameplay_running, spawn_speed_buttons},
+    i18n::{Localization, Localized},
+    settings::Settings,
 };
 
 // --- Composants ---
@@ -25,13 +33,40 @@ use crate::{
 
 #[derive(Resource)]
 struct SimState {
-    start_time: f64,
+    elapsed: f64, // Temps réel écoulé hors pause : la durée ne dépend pas de la vitesse du jeu
     total_spawned: u32,
-    last_log_time: f64,
     spawn_timer: Timer, 
     finished: bool,
 }
 
+// Fenêtre de mesure : frames comptées une fois la foule assez nombreuse pour que la comparaison ait un sens
+const BENCH_FRAMES: u32 = 600;
+const BENCH_MIN_ENEMIES: usize = 1000;
+
+// Mesure du coût du mouvement : pendant la fenêtre de mesure seulement, on alterne séquentiel / parallèle
+// une frame sur deux pour comparer les deux versions sur la même charge ; ensuite tout est parallèle
+#[derive(Resource, Default)]
+struct MoveBenchmark {
+    measured_frames: u32,
+    seq_nanos: u128,
+    seq_updates: u64,
+    par_nanos: u128,
+    par_updates: u64,
+}
+
+impl MoveBenchmark {
+    fn seq_ns_per_enemy(&self) -> f64 { if self.seq_updates == 0 { 0.0 } else { self.seq_nanos as f64 / self.seq_updates as f64 } }
+    fn par_ns_per_enemy(&self) -> f64 { if self.par_updates == 0 { 0.0 } else { self.par_nanos as f64 / self.par_updates as f64 } }
+    fn speedup(&self) -> f64 { let p = self.par_ns_per_enemy(); if p == 0.0 { 0.0 } else { self.seq_ns_per_enemy() / p } }
+
+    fn report(&self) {
+        println!("--- Rapport mouvement (Simulation, {} frames à partir de {} ennemis) ---", self.measured_frames, BENCH_MIN_ENEMIES);
+        println!("Séquentiel : {:.1} ns/ennemi ({} mises à jour)", self.seq_ns_per_enemy(), self.seq_updates);
+        println!("Parallèle  : {:.1} ns/ennemi ({} mises à jour)", self.par_ns_per_enemy(), self.par_updates);
+        println!("Accélération : x{:.2}", self.speedup());
+    }
+}
+
 pub struct SimulationPlugin;
 
 impl Plugin for SimulationPlugin {
@@ -46,68 +81,60 @@ impl Plugin for SimulationPlugin {
                     simulation_logic, 
                     move_sim_enemies_loop, 
                     update_sim_visuals, // Rotation + HealthBars
-                    tower_shooting,
-                    cleanup_dead_sim_enemies // Mort simple (sans argent)
-                ).run_if(not_paused), 
+                    tower_shooting, // Les morts passent par EnemyKilled (sans argent)
+                ).run_if(gameplay_running), 
             ).run_if(in_state(AppState::Simulation)))
             .add_systems(OnExit(AppState::Simulation), cleanup_simulation);
     }
 }
 
-fn not_paused(pause: Res<GlobalPause>) -> bool { !pause.0 }
-
 // --- Setup ---
-fn setup_simulation(mut commands: Commands, assets: Res<GameAssets>, mut pause: ResMut<GlobalPause>, playing_entities: Query<Entity, With<crate::game::GameComponent>>, existing_sim: Query<Entity, With<SimComponent>>) {
+fn setup_simulation(mut commands: Commands, atlas: Res<SpriteAtlas>, loc: Res<Localization>, mut speed: ResMut<GameSpeed>, playing_entities: Query<Entity, With<crate::game::GameComponent>>, existing_sim: Query<Entity, With<SimComponent>>) {
     println!("=== DÉMARRAGE BENCHMARK (Mode Stress Test) ===");
-    pause.0 = false;
+    speed.reset(SpeedSetting::Normal);
 
     for e in playing_entities.iter() { commands.entity(e).despawn_recursive(); }
     for e in existing_sim.iter() { commands.entity(e).despawn_recursive(); }
 
     let level_data = level::get_level_data();
-    const TILE_SIZE: f32 = 32.0;
-    const MAP_WIDTH: f32 = 20.0 * TILE_SIZE;
-    const MAP_HEIGHT: f32 = 20.0 * TILE_SIZE;
-    let vertical_shift = 50.0;
-    let x_offset = -MAP_WIDTH / 2.0 + TILE_SIZE / 2.0;
-    let y_offset = (MAP_HEIGHT / 2.0 - TILE_SIZE / 2.0) + vertical_shift;
     let mut start_pos = Vec2::ZERO;
 
     // 1. Map & Tours
     for (y, row) in level_data.iter().enumerate() {
         for (x, &tile_id) in row.iter().enumerate() {
-            let pos = Vec2::new(x_offset + x as f32 * TILE_SIZE, y_offset - y as f32 * TILE_SIZE);
+            let pos = level::tile_to_world(x, y);
             let tile_type = get_tile_type(tile_id);
             
-            let mut spawn_tile = |idx: usize, rot: Quat, z: f32| {
+            let mut spawn_tile = |name: &str, rot: Quat, z: f32| {
                 commands.spawn((
                     SpriteSheetBundle {
-                        texture: assets.sprite_atlas.clone(),
-                        atlas: TextureAtlas { layout: assets.sprite_atlas_layout.clone(), index: idx },
+                        texture: atlas.image.clone(),
+                        atlas: atlas.sprite(name),
                         transform: Transform { translation: pos.extend(z), rotation: rot, ..default() },
                         ..default()
                     }, SimComponent,
                 ));
             };
 
-            match tile_id {
-                8..=19 => { spawn_tile(get_atlas_index(0,0), Quat::IDENTITY, 0.0); let (i,r) = get_composite_info(tile_id); spawn_tile(i,r,0.1); },
-                20|21 => { spawn_tile(get_atlas_index(8,0), Quat::IDENTITY, 0.0); let i = if tile_id==20 { get_atlas_index(7,2) } else { get_atlas_index(8,2) }; spawn_tile(i, Quat::IDENTITY, 0.1); },
-                _ => { let (i,r) = get_simple_tile_info(tile_id); spawn_tile(i,r,0.0); }
+            let sprites = tile_sprites(tile_id);
+            match sprites.overlay {
+                Some(overlay) => { spawn_tile(sprites.base, Quat::IDENTITY, 0.0); spawn_tile(overlay, sprites.rotation, 0.1); },
+                None => spawn_tile(sprites.base, sprites.rotation, 0.0),
             }
             if tile_id == 20 { start_pos = pos; }
 
             if tile_type == TileType::Grass {
                 let tower_type = determine_sim_tower_type(x, y, level_data);
-                let (range, damage, cooldown) = tower_type.get_sim_stats();
                 commands.spawn((
                     SpriteSheetBundle {
-                        texture: assets.sprite_atlas.clone(),
-                        atlas: TextureAtlas { layout: assets.sprite_atlas_layout.clone(), index: tower_type.get_sprite_index() },
+                        texture: atlas.image.clone(),
+                        atlas: atlas.sprite(tower_type.sprite_name()),
                         transform: Transform::from_xyz(pos.x, pos.y, 2.0),
                         ..default()
                     },
-                    Tower { range, damage, cooldown: Timer::from_seconds(cooldown, TimerMode::Repeating) },
+                    Tower::new(tower_type, MAX_TIER), // Stats Tier 3 (get_sim_stats)
+                    tower_type,
+                    SpriteAnimator::default(),
                     SimComponent,
                 ));
             }
@@ -117,8 +144,8 @@ fn setup_simulation(mut commands: Commands, assets: Res<GameAssets>, mut pause:
     // 2. Pathfinding
     let mut path_points = Vec::new();
     path_points.push(start_pos);
-    let mut grid_x = ((start_pos.x - x_offset) / TILE_SIZE).round() as i32;
-    let mut grid_y = ((y_offset - start_pos.y) / TILE_SIZE).round() as i32;
+    let (grid_x, grid_y) = level::world_to_tile(start_pos).expect("départ hors de la carte");
+    let (mut grid_x, mut grid_y) = (grid_x as i32, grid_y as i32);
     let mut last_grid_pos = (grid_x, grid_y);
     for _ in 0..100 {
         let mut found_next = false;
@@ -134,7 +161,7 @@ fn setup_simulation(mut commands: Commands, assets: Res<GameAssets>, mut pause:
                 last_grid_pos = (grid_x, grid_y);
                 grid_x = nx;
                 grid_y = ny;
-                path_points.push(Vec2::new(x_offset + nx as f32 * TILE_SIZE, y_offset - ny as f32 * TILE_SIZE));
+                path_points.push(level::tile_to_world(nx as usize, ny as usize));
                 found_next = true;
                 if ttype == TileType::End { break; }
                 break;
@@ -153,9 +180,13 @@ fn setup_simulation(mut commands: Commands, assets: Res<GameAssets>, mut pause:
             background_color: bar_color.into(), ..default()
         }, SimComponent,
     )).with_children(|parent| {
-        spawn_ui_button(parent, "MENU", BtnMenu);
-        spawn_ui_button(parent, "PAUSE", BtnPause);
-        parent.spawn((TextBundle::from_section("Init...", TextStyle { font_size: 20.0, color: Color::BLACK, ..default() }).with_style(Style { margin: UiRect::left(Val::Px(50.0)), ..default() }), SimStatsText));
+        spawn_ui_button(parent, "button.menu", BtnMenu);
+        parent.spawn(NodeBundle { style: Style { flex_direction: FlexDirection::Column, ..default() }, ..default() })
+            .with_children(|col| {
+                spawn_ui_button(col, "button.pause", BtnPause);
+                spawn_speed_buttons(col);
+            });
+        parent.spawn((TextBundle::from_section(loc.get("sim.init"), TextStyle { font_size: 20.0, color: Color::BLACK, ..default() }).with_style(Style { margin: UiRect::left(Val::Px(50.0)), ..default() }), SimStatsText));
     });
 
     commands.spawn((
@@ -164,22 +195,22 @@ fn setup_simulation(mut commands: Commands, assets: Res<GameAssets>, mut pause:
             background_color: Color::rgba(0.0, 0.0, 0.0, 0.5).into(), z_index: ZIndex::Global(100), ..default()
         }, PauseOverlay, SimComponent,
     )).with_children(|parent| {
-        parent.spawn(TextBundle::from_section("SIMULATION PAUSE", TextStyle { font_size: 50.0, color: Color::WHITE, ..default() }));
+        parent.spawn((TextBundle::from_section("", TextStyle { font_size: 50.0, color: Color::WHITE, ..default() }), Localized("sim.paused")));
     });
 
     commands.insert_resource(SimState {
-        start_time: 0.0, total_spawned: 0, last_log_time: 0.0,
+        elapsed: 0.0, total_spawned: 0,
         spawn_timer: Timer::from_seconds(1.0 / 60.0, TimerMode::Repeating),
         finished: false,
     });
+    commands.insert_resource(MoveBenchmark::default());
 }
 
 // --- Logic ---
-fn simulation_logic(mut commands: Commands, assets: Res<GameAssets>, mut sim_state: ResMut<SimState>, path: Res<Path>, time: Res<Time>) {
-    if sim_state.start_time == 0.0 { sim_state.start_time = time.elapsed_seconds_f64(); }
+fn simulation_logic(mut commands: Commands, atlas: Res<SpriteAtlas>, mut sim_state: ResMut<SimState>, bench: Res<MoveBenchmark>, path: Res<Path>, time: Res<Time>, real_time: Res<Time<Real>>) {
     if sim_state.finished || path.points.is_empty() { return; }
-    let elapsed = time.elapsed_seconds_f64() - sim_state.start_time;
-    if elapsed >= 5.0 * 60.0 { println!("FIN DE LA SIMULATION"); sim_state.finished = true; return; }
+    sim_state.elapsed += real_time.delta_seconds_f64();
+    if sim_state.elapsed >= 5.0 * 60.0 { println!("FIN DE LA SIMULATION"); bench.report(); sim_state.finished = true; return; }
 
     sim_state.spawn_timer.tick(time.delta());
     let ticks = sim_state.spawn_timer.times_finished_this_tick();
@@ -189,15 +220,15 @@ fn simulation_logic(mut commands: Commands, assets: Res<GameAssets>, mut sim_sta
         sim_state.total_spawned += to_spawn;
         for _ in 0..to_spawn {
             commands.spawn((
-                SpriteSheetBundle { texture: assets.sprite_atlas.clone(), atlas: TextureAtlas { layout: assets.sprite_atlas_layout.clone(), index: 10 }, transform: Transform::from_xyz(start_pos.x, start_pos.y, 1.0), ..default() },
-                Enemy { speed: 50.0 }, Health { current: 85, max: 85 }, SimPathFollower { path_index: 1 }, SimComponent,
+                SpriteSheetBundle { texture: atlas.image.clone(), atlas: atlas.sprite(EnemyConstants::get_sprite_name(EnemyConstants::ORC)), transform: Transform::from_xyz(start_pos.x, start_pos.y, 1.0), ..default() },
+                Enemy { enemy_type: EnemyConstants::ORC, speed: 50.0 }, Health { current: 85, max: 85 }, PendingDamage::default(), Velocity::default(), SpriteAnimator::playing(atlas.clip(clips::walk(EnemyConstants::ORC))), SimPathFollower { path_index: 1 }, SimComponent,
             )).with_children(|parent| {
-                parent.spawn(SpriteBundle { 
+                parent.spawn((SpriteBundle { 
                     sprite: Sprite { color: Color::BLACK, custom_size: Some(Vec2::new(22.0, 6.0)), ..default() }, 
                     transform: Transform::from_xyz(0.0, 20.0, 0.1), 
                     visibility: Visibility::Hidden, // CACHÉ PAR DÉFAUT
                     ..default() 
-                });
+                }, HealthBarBackground));
                 parent.spawn((SpriteBundle { 
                     sprite: Sprite { color: Color::RED, custom_size: Some(Vec2::new(20.0, 4.0)), ..default() }, 
                     transform: Transform::from_xyz(0.0, 20.0, 0.2), 
@@ -209,37 +240,53 @@ fn simulation_logic(mut commands: Commands, assets: Res<GameAssets>, mut sim_sta
     }
 }
 
-fn move_sim_enemies_loop(mut query: Query<(&mut Transform, &Enemy, &mut SimPathFollower)>, path: Res<Path>, time: Res<Time>) {
+fn move_sim_enemies_loop(mut query: Query<(&mut Transform, &Enemy, &mut SimPathFollower, &mut Velocity)>, path: Res<Path>, time: Res<Time>, mut bench: ResMut<MoveBenchmark>) {
     if path.points.is_empty() { return; }
-    for (mut transform, enemy, mut follower) in query.iter_mut() {
-        if follower.path_index >= path.points.len() {
+    let dt = time.delta_seconds();
+    let points = &path.points;
+
+    // Pas d'effet de bord : l'ennemi arrivé au bout reboucle au départ
+    let move_one = |(mut transform, enemy, mut follower, mut velocity): (Mut<Transform>, &Enemy, Mut<SimPathFollower>, Mut<Velocity>)| {
+        if follower.path_index >= points.len() {
             follower.path_index = 1;
-            let start = path.points[0];
-            transform.translation.x = start.x;
-            transform.translation.y = start.y;
-            continue;
-        }
-        let target = path.points[follower.path_index];
-        let dir = target - transform.translation.truncate();
-        let dist = dir.length();
-        let step = enemy.speed * time.delta_seconds();
-        if dist <= step {
-            transform.translation.x = target.x;
-            transform.translation.y = target.y;
-            follower.path_index += 1;
-        } else {
-            let mv = dir.normalize() * step;
-            transform.translation.x += mv.x;
-            transform.translation.y += mv.y;
+            transform.translation.x = points[0].x;
+            transform.translation.y = points[0].y;
+            return;
         }
+        let moved = step_along_path(&mut transform.translation, &mut follower.path_index, points, enemy.speed * dt);
+        if dt > 0.0 { velocity.0 = moved / dt; }
+    };
+
+    let count = query.iter().len();
+    let measuring = bench.measured_frames < BENCH_FRAMES && count >= BENCH_MIN_ENEMIES;
+    // Version séquentielle : uniquement une frame sur deux de la fenêtre de mesure, pour la comparaison
+    if measuring && bench.measured_frames.is_multiple_of(2) {
+        let start = Instant::now();
+        query.iter_mut().for_each(move_one);
+        bench.seq_nanos += start.elapsed().as_nanos();
+        bench.seq_updates += count as u64;
+        bench.measured_frames += 1;
+        return;
+    }
+
+    let start = Instant::now();
+    query
+        .par_iter_mut()
+        .batching_strategy(BatchingStrategy::new().min_batch_size(ENEMY_BATCH_SIZE))
+        .for_each(move_one);
+    if measuring {
+        bench.par_nanos += start.elapsed().as_nanos();
+        bench.par_updates += count as u64;
+        bench.measured_frames += 1;
     }
 }
 
 // Système visuel pour la simulation (Rotation + HP)
 fn update_sim_visuals(
     mut enemies: Query<(&mut Transform, &SimPathFollower), With<Enemy>>,
-    mut bars: Query<(&mut Transform, &Parent, &mut Visibility), (With<HealthBar>, Without<Enemy>)>,
+    mut bars: Query<(&mut Transform, &Parent, &mut Visibility, Has<HealthBar>), (Or<(With<HealthBar>, With<HealthBarBackground>)>, Without<Enemy>)>,
     health_q: Query<&Health>,
+    settings: Res<Settings>,
     path: Res<Path>
 ) {
     if !path.points.is_empty() {
@@ -253,14 +300,14 @@ fn update_sim_visuals(
         }
     }
     
-    for (mut t, parent, mut vis) in bars.iter_mut() {
+    for (mut t, parent, mut vis, is_bar) in bars.iter_mut() {
         if let Ok(h) = health_q.get(parent.get()) {
-            // Si 100% HP -> Caché
-            if h.current >= h.max {
+            // Selon l'option du joueur (par défaut : seulement si blessé)
+            if !settings.health_bars.shows(h.current, h.max) {
                 *vis = Visibility::Hidden;
             } else {
-                *vis = Visibility::Inherited; // Affiche si blessé
-                if h.max > 0 { 
+                *vis = Visibility::Inherited;
+                if is_bar && h.max > 0 { 
                     t.scale.x = (h.current as f32 / h.max as f32).clamp(0.0, 1.0); 
                 }
             }
@@ -268,47 +315,47 @@ fn update_sim_visuals(
     }
 }
 
-fn cleanup_dead_sim_enemies(
-    mut commands: Commands, 
-    query: Query<(Entity, &Health), With<SimComponent>>
-) {
-    for (entity, health) in query.iter() {
-        if health.current <= 0 {
-            // Pas de log pour ne pas spammer la console en benchmark, mais ça marche
-            commands.entity(entity).despawn_recursive();
-        }
-    }
-}
-
 // --- UI ---
-fn ui_interaction(mut cmd: Commands, mut sim: ResMut<SimState>, mut q: Query<(&Interaction, Option<&BtnMenu>, Option<&BtnPause>), (Changed<Interaction>, With<Button>)>, mut next: ResMut<NextState<AppState>>, mut pause: ResMut<GlobalPause>) {
+fn ui_interaction(mut q: Query<(&Interaction, Option<&BtnMenu>, Option<&BtnPause>), (Changed<Interaction>, With<Button>)>, actions: Res<ActionState>, mut next: ResMut<NextState<AppState>>, mut speed: ResMut<GameSpeed>) {
+    if actions.just_pressed(InputAction::BackToMenu) { next.set(AppState::Menu); }
+    if actions.just_pressed(InputAction::Pause) { speed.toggle_pause(); }
     for (int, btn_menu, btn_pause) in q.iter_mut() {
         if *int == Interaction::Pressed {
-            if btn_menu.is_some() { pause.0 = false; next.set(AppState::Menu); }
-            else if btn_pause.is_some() { pause.0 = !pause.0; }
+            if btn_menu.is_some() { next.set(AppState::Menu); }
+            else if btn_pause.is_some() { speed.toggle_pause(); }
         }
     }
 }
 
-fn update_ui_text(time: Res<Time>, diag: Res<DiagnosticsStore>, sim: Res<SimState>, enemies: Query<Entity, With<Enemy>>, mut txt: Query<&mut Text, With<SimStatsText>>, mut btn: Query<&mut Text, (With<BtnPause>, Without<SimStatsText>)>, mut over: Query<&mut Style, With<PauseOverlay>>, pause: Res<GlobalPause>) {
+fn update_ui_text(diag: Res<DiagnosticsStore>, loc: Res<Localization>, sim: Res<SimState>, bench: Res<MoveBenchmark>, enemies: Query<Entity, With<Enemy>>, mut txt: Query<&mut Text, With<SimStatsText>>, btn: Query<&Children, With<BtnPause>>, mut btn_labels: Query<&mut Localized>, mut over: Query<&mut Style, With<PauseOverlay>>, speed: Res<GameSpeed>) {
     let fps = diag.get(&FrameTimeDiagnosticsPlugin::FPS).and_then(|f| f.smoothed()).unwrap_or(0.0);
     let count = enemies.iter().count();
-    let elapsed = time.elapsed_seconds_f64() - sim.start_time;
+    let elapsed = sim.elapsed;
     let mins = (elapsed / 60.0) as u32;
     let secs = (elapsed % 60.0) as u32;
 
     for mut t in txt.iter_mut() {
-        t.sections[0].value = format!("Temps: {:02}:{:02} / 05:00   Total Spawnés: {}   Actifs: {}\nFPS: {:.1}", mins, secs, sim.total_spawned, count, fps);
+        t.sections[0].value = loc.format("sim.stats", &[
+            &format!("{:02}:{:02}", mins, secs), &sim.total_spawned, &count, &format!("{:.1}", fps),
+            &format!("{:.0}", bench.seq_ns_per_enemy()), &format!("{:.0}", bench.par_ns_per_enemy()), &format!("{:.2}", bench.speedup()),
+        ]);
+    }
+    for children in btn.iter() {
+        let key = if speed.paused { "button.resume" } else { "button.pause" };
+        let mut iter = btn_labels.iter_many_mut(children);
+        while let Some(mut label) = iter.fetch_next() { if label.0 != key { label.0 = key; } }
     }
-    for mut t in btn.iter_mut() { t.sections[0].value = if pause.0 { "RESUME".into() } else { "PAUSE".into() }; }
-    for mut s in over.iter_mut() { s.display = if pause.0 { Display::Flex } else { Display::None }; }
+    for mut s in over.iter_mut() { s.display = if speed.paused { Display::Flex } else { Display::None }; }
 }
 
 fn cleanup_simulation(
     mut commands: Commands, 
+    sim: Option<Res<SimState>>,
+    bench: Option<Res<MoveBenchmark>>,
+    mut speed: ResMut<GameSpeed>,
     query: Query<Entity, With<SimComponent>>,
-    // On nettoie aussi les projectiles qui traînent
-    projectiles: Query<Entity, With<Projectile>>,
+    // On nettoie aussi les projectiles qui traînent (en vol ou au sol)
+    projectiles: Query<Entity, Or<(With<Projectile>, With<LandedProjectile>)>>,
 ) {
     for entity in query.iter() {
         commands.entity(entity).despawn_recursive();
@@ -316,13 +363,19 @@ fn cleanup_simulation(
     for entity in projectiles.iter() {
         commands.entity(entity).despawn_recursive();
     }
+    // Rapport aussi en cas de sortie avant la fin des 5 minutes
+    if let (Some(sim), Some(bench)) = (sim, bench) && !sim.finished {
+        bench.report();
+    }
     commands.remove_resource::<Path>();
     commands.remove_resource::<SimState>();
+    commands.remove_resource::<MoveBenchmark>();
+    speed.reset(SpeedSetting::Normal);
 }
 
 // --- Helpers ---
-fn spawn_ui_button<T: Component>(parent: &mut ChildBuilder, text: &str, marker: T) {
-    parent.spawn((ButtonBundle { style: Style { width: Val::Px(80.0), height: Val::Px(30.0), margin: UiRect::right(Val::Px(10.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(2.0)), ..default() }, background_color: Color::rgb(0.33, 0.23, 0.15).into(), border_color: BorderColor(Color::BLACK), ..default() }, marker)).with_children(|p| { p.spawn(TextBundle::from_section(text, TextStyle { font_size: 16.0, color: Color::rgb(0.9, 0.85, 0.7), ..default() })); });
+fn spawn_ui_button<T: Component>(parent: &mut ChildBuilder, key: &'static str, marker: T) {
+    parent.spawn((ButtonBundle { style: Style { width: Val::Px(80.0), height: Val::Px(30.0), margin: UiRect::right(Val::Px(10.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(2.0)), ..default() }, background_color: Color::rgb(0.33, 0.23, 0.15).into(), border_color: BorderColor(Color::BLACK), ..default() }, marker)).with_children(|p| { p.spawn((TextBundle::from_section("", TextStyle { font_size: 16.0, color: Color::rgb(0.9, 0.85, 0.7), ..default() }), Localized(key))); });
 }
 fn determine_sim_tower_type(x: usize, y: usize, level: &[[u32; 20]; 20]) -> TowerType {
     let neighbors = [(0, -1), (0, 1), (-1, 0), (1, 0)];
@@ -338,15 +391,3 @@ fn determine_sim_tower_type(x: usize, y: usize, level: &[[u32; 20]; 20]) -> Towe
     if next_to_road { return TowerType::Canon; }
     TowerType::Archer
 }
-fn get_composite_info(id: u32) -> (usize, Quat) {
-    let r90 = -90.0f32.to_radians(); let r180 = 180.0f32.to_radians(); let r270 = 90.0f32.to_radians();
-    match id {
-        8 => (get_atlas_index(5, 0), Quat::IDENTITY), 9 => (get_atlas_index(5, 0), Quat::from_rotation_z(r90)), 10 => (get_atlas_index(5, 0), Quat::from_rotation_z(r180)), 11 => (get_atlas_index(5, 0), Quat::from_rotation_z(r270)), 12 => (get_atlas_index(6, 0), Quat::IDENTITY), 13 => (get_atlas_index(6, 0), Quat::from_rotation_z(r90)), 14 => (get_atlas_index(6, 0), Quat::from_rotation_z(r180)), 15 => (get_atlas_index(6, 0), Quat::from_rotation_z(r270)), 16 => (get_atlas_index(4, 0), Quat::IDENTITY), 17 => (get_atlas_index(4, 0), Quat::from_rotation_z(r90)), 18 => (get_atlas_index(4, 0), Quat::from_rotation_z(r180)), 19 => (get_atlas_index(4, 0), Quat::from_rotation_z(r270)), _ => (0, Quat::IDENTITY),
-    }
-}
-fn get_simple_tile_info(id: u32) -> (usize, Quat) {
-    let r90 = -90.0f32.to_radians(); let r180 = 180.0f32.to_radians(); let r270 = 90.0f32.to_radians();
-    match id {
-        0 => (get_atlas_index(9, 0), Quat::IDENTITY), 1 => (get_atlas_index(0, 0), Quat::IDENTITY), 2 => (get_atlas_index(8, 0), Quat::IDENTITY), 3 => (get_atlas_index(8, 0), Quat::from_rotation_z(r90)), 4 => (get_atlas_index(7, 0), Quat::IDENTITY), 5 => (get_atlas_index(7, 0), Quat::from_rotation_z(r90)), 6 => (get_atlas_index(7, 0), Quat::from_rotation_z(r180)), 7 => (get_atlas_index(7, 0), Quat::from_rotation_z(r270)), _ => (get_atlas_index(0, 0), Quat::IDENTITY),
-    }
-}
\ No newline at end of file
@@ -0,0 +1,329 @@
+// src/sound.rs
+// Sons du jeu, synthétisés à la volée (pas de fichiers audio) :
+// - effets déclenchés par les événements (tirs, impacts, morts, fuites, vagues, achat refusé)
+// - musique en boucle, différente dans les menus et en jeu
+// Volumes réglés dans les options (général x musique / effets). Le nombre de sons simultanés
+// est limité pour que les centaines de tirs de la simulation ne saturent pas le mixeur.
+// Sans carte son, les sons ne jouent pas mais rien ne s'accumule (chaque effet a sa durée de vie)
+
+use std::{f32::consts::TAU, sync::Arc, time::Duration};
+use bevy::{
+    prelude::*,
+    audio::{AddAudioSource, AudioPlugin, Decodable, Source, Volume},
+    utils::HashMap,
+};
+use crate::{
+    AppState,
+    settings::Settings,
+    tower::TowerType,
+    core::{TowerFired, ProjectileHit, EnemyKilled, EnemyLeaked, WaveStarted, PurchaseDenied},
+    constants::enemies::{BAT, KNIGHT, WOLF},
+};
+
+const SAMPLE_RATE: u32 = 22_050;
+const ATTACK_TIME: f32 = 0.005; // s
+
+// Limites du mixeur
+const MAX_VOICES: usize = 16;      // Effets joués en même temps, tous types confondus
+const MAX_SAME_SOUND: usize = 3;   // Effets identiques joués en même temps
+const MIN_REPEAT_TIME: f32 = 0.05; // s entre deux départs du même effet
+
+const MUSIC_VOLUME: f32 = 0.5; // Musique un peu en retrait des effets
+
+#[derive(Debug, Clone, Copy)]
+enum Waveform {
+    Sine,
+    Square,
+    Triangle,
+    Noise,
+}
+
+// Une note : glissement de fréquence (Hz) pendant sa durée (s), volume de 0 à 1
+#[derive(Debug, Clone, Copy)]
+struct Tone {
+    from: f32,
+    to: f32,
+    duration: f32,
+    volume: f32,
+}
+
+const fn tone(from: f32, to: f32, duration: f32, volume: f32) -> Tone {
+    Tone { from, to, duration, volume }
+}
+
+// Son synthétisé : une forme d'onde et une suite de notes
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct Synth {
+    waveform: Waveform,
+    tones: Arc<[Tone]>,
+}
+
+impl Synth {
+    fn new(waveform: Waveform, tones: &[Tone]) -> Self {
+        Self { waveform, tones: tones.into() }
+    }
+
+    fn duration(&self) -> f32 {
+        self.tones.iter().map(|t| t.duration).sum()
+    }
+}
+
+pub struct SynthDecoder {
+    synth: Synth,
+    tone: usize,
+    sample: u32,
+    phase: f32,
+    noise: u32,
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        loop {
+            let tone = *self.synth.tones.get(self.tone)?;
+            let length = (tone.duration * SAMPLE_RATE as f32) as u32;
+            if self.sample >= length {
+                self.tone += 1;
+                self.sample = 0;
+                continue;
+            }
+            let t = self.sample as f32 / length as f32;
+            let frequency = tone.from + (tone.to - tone.from) * t;
+            self.phase = (self.phase + frequency / SAMPLE_RATE as f32).fract();
+            let value = match self.synth.waveform {
+                Waveform::Sine => (self.phase * TAU).sin(),
+                Waveform::Square => if self.phase < 0.5 { 1.0 } else { -1.0 },
+                Waveform::Triangle => 4.0 * (self.phase - 0.5).abs() - 1.0,
+                Waveform::Noise => {
+                    // Générateur congruentiel : même bruit à chaque lecture
+                    self.noise = self.noise.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                    (self.noise >> 8) as f32 / (1u32 << 23) as f32 - 1.0
+                }
+            };
+            // Enveloppe : attaque courte puis décroissance jusqu'à la fin de la note
+            let attack = (self.sample as f32 / (ATTACK_TIME * SAMPLE_RATE as f32)).min(1.0);
+            self.sample += 1;
+            return Some(value * tone.volume * attack * (1.0 - t));
+        }
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(self.synth.duration()))
+    }
+}
+
+impl Decodable for Synth {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> SynthDecoder {
+        SynthDecoder { synth: self.clone(), tone: 0, sample: 0, phase: 0.0, noise: 0x2545_F491 }
+    }
+}
+
+// Effets sonores
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Sfx {
+    Fire(TowerType),
+    Impact,
+    Death(u32), // Par type d'ennemi
+    Leak,
+    WaveStart,
+    PurchaseDenied,
+}
+
+impl Sfx {
+    fn synth(&self) -> Synth {
+        match self {
+            Sfx::Fire(TowerType::Canon) => Synth::new(Waveform::Square, &[tone(180.0, 60.0, 0.18, 0.35)]),
+            Sfx::Fire(TowerType::Archer) => Synth::new(Waveform::Triangle, &[tone(900.0, 600.0, 0.06, 0.3)]),
+            Sfx::Fire(TowerType::Wizard) => Synth::new(Waveform::Sine, &[tone(600.0, 1200.0, 0.15, 0.3)]),
+            Sfx::Impact => Synth::new(Waveform::Noise, &[tone(0.0, 0.0, 0.08, 0.25)]),
+            Sfx::Death(enemy_type) => match *enemy_type {
+                BAT => Synth::new(Waveform::Sine, &[tone(1200.0, 800.0, 0.12, 0.35)]),
+                KNIGHT => Synth::new(Waveform::Square, &[tone(140.0, 70.0, 0.3, 0.35)]),
+                WOLF => Synth::new(Waveform::Triangle, &[tone(500.0, 250.0, 0.2, 0.4)]),
+                _ => Synth::new(Waveform::Square, &[tone(220.0, 110.0, 0.2, 0.3)]), // ORC
+            },
+            Sfx::Leak => Synth::new(Waveform::Square, &[tone(400.0, 300.0, 0.15, 0.4), tone(200.0, 150.0, 0.3, 0.4)]),
+            Sfx::WaveStart => Synth::new(Waveform::Triangle, &[tone(440.0, 440.0, 0.12, 0.4), tone(554.0, 554.0, 0.12, 0.4), tone(659.0, 659.0, 0.25, 0.4)]),
+            Sfx::PurchaseDenied => Synth::new(Waveform::Square, &[tone(150.0, 150.0, 0.08, 0.3), tone(0.0, 0.0, 0.04, 0.0), tone(150.0, 150.0, 0.12, 0.3)]),
+        }
+    }
+}
+
+// Musique de fond
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MusicTrack {
+    Menu,
+    Gameplay,
+}
+
+impl MusicTrack {
+    fn for_state(state: &AppState) -> MusicTrack {
+        match state {
+            AppState::Playing | AppState::Simulation => MusicTrack::Gameplay,
+            _ => MusicTrack::Menu,
+        }
+    }
+
+    fn synth(&self) -> Synth {
+        match self {
+            // Arpège lent en la mineur
+            MusicTrack::Menu => {
+                let notes = [220.0, 261.6, 329.6, 440.0, 329.6, 261.6, 196.0, 246.9, 293.7, 392.0, 293.7, 246.9];
+                Synth::new(Waveform::Sine, &notes.map(|f| tone(f, f, 0.4, 0.25)))
+            }
+            // Basse plus rapide
+            MusicTrack::Gameplay => {
+                let notes = [110.0, 110.0, 130.8, 110.0, 146.8, 110.0, 164.8, 146.8, 98.0, 98.0, 130.8, 98.0, 146.8, 130.8, 123.5, 98.0];
+                Synth::new(Waveform::Triangle, &notes.map(|f| tone(f, f, 0.2, 0.3)))
+            }
+        }
+    }
+}
+
+// Effet en cours : supprimé à la fin de sa durée, que le son ait pu jouer ou non
+#[derive(Component)]
+struct SoundEffect {
+    sfx: Sfx,
+    lifetime: Timer,
+}
+
+#[derive(Component)]
+struct Music(MusicTrack);
+
+// Sons déjà synthétisés, et dernier départ de chaque effet (temps réel)
+#[derive(Resource, Default)]
+struct SoundLibrary {
+    effects: HashMap<Sfx, Handle<Synth>>,
+    last_played: HashMap<Sfx, f32>,
+}
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        // Sans AudioPlugin (tests, serveur), les sons sont créés mais jamais joués
+        if app.is_plugin_added::<AudioPlugin>() {
+            app.add_audio_source::<Synth>();
+        } else {
+            app.init_asset::<Synth>();
+        }
+        app
+            .init_resource::<SoundLibrary>()
+            .add_systems(Update, (play_event_sounds, expire_sound_effects, update_music, update_music_volume));
+    }
+}
+
+// Lance un effet si le mixeur le permet
+fn play(
+    commands: &mut Commands,
+    library: &mut SoundLibrary,
+    synths: &mut Assets<Synth>,
+    playing: &mut Vec<Sfx>,
+    now: f32,
+    volume: f32,
+    sfx: Sfx,
+) {
+    if volume <= 0.0 || playing.len() >= MAX_VOICES { return; }
+    if playing.iter().filter(|s| **s == sfx).count() >= MAX_SAME_SOUND { return; }
+    if library.last_played.get(&sfx).is_some_and(|last| now - last < MIN_REPEAT_TIME) { return; }
+
+    let synth = sfx.synth();
+    let duration = synth.duration();
+    let handle = library.effects.entry(sfx).or_insert_with(|| synths.add(synth)).clone();
+    library.last_played.insert(sfx, now);
+    playing.push(sfx);
+    commands.spawn((
+        AudioSourceBundle { source: handle, settings: PlaybackSettings::ONCE.with_volume(Volume::new(volume)) },
+        SoundEffect { sfx, lifetime: Timer::from_seconds(duration, TimerMode::Once) },
+    ));
+}
+
+fn play_event_sounds(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    settings: Res<Settings>,
+    mut library: ResMut<SoundLibrary>,
+    mut synths: ResMut<Assets<Synth>>,
+    effects: Query<&SoundEffect>,
+    mut fired: EventReader<TowerFired>,
+    mut hits: EventReader<ProjectileHit>,
+    mut killed: EventReader<EnemyKilled>,
+    mut leaked: EventReader<EnemyLeaked>,
+    mut waves: EventReader<WaveStarted>,
+    mut denied: EventReader<PurchaseDenied>,
+) {
+    let sounds: Vec<Sfx> = fired.read().map(|e| Sfx::Fire(e.tower_type))
+        .chain(hits.read().map(|_| Sfx::Impact))
+        .chain(killed.read().map(|e| Sfx::Death(e.enemy_type)))
+        .chain(leaked.read().map(|_| Sfx::Leak))
+        .chain(waves.read().map(|_| Sfx::WaveStart))
+        .chain(denied.read().map(|_| Sfx::PurchaseDenied))
+        .collect();
+    if sounds.is_empty() { return; }
+
+    let volume = settings.volume as f32 / 100.0 * settings.effects_volume as f32 / 100.0;
+    let now = time.elapsed_seconds();
+    let mut playing: Vec<Sfx> = effects.iter().map(|e| e.sfx).collect();
+    for sfx in sounds {
+        play(&mut commands, &mut library, &mut synths, &mut playing, now, volume, sfx);
+    }
+}
+
+fn expire_sound_effects(mut commands: Commands, time: Res<Time<Real>>, mut effects: Query<(Entity, &mut SoundEffect)>) {
+    for (entity, mut effect) in effects.iter_mut() {
+        effect.lifetime.tick(time.delta());
+        if effect.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn music_volume(settings: &Settings) -> f32 {
+    MUSIC_VOLUME * settings.volume as f32 / 100.0 * settings.music_volume as f32 / 100.0
+}
+
+// Change de morceau quand on passe des menus au jeu (et inversement)
+fn update_music(
+    mut commands: Commands,
+    state: Res<State<AppState>>,
+    settings: Res<Settings>,
+    mut synths: ResMut<Assets<Synth>>,
+    music: Query<(Entity, &Music)>,
+) {
+    if !state.is_changed() { return; }
+    let track = MusicTrack::for_state(state.get());
+    if music.iter().any(|(_, m)| m.0 == track) { return; }
+    for (entity, _) in music.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.spawn((
+        AudioSourceBundle { source: synths.add(track.synth()), settings: PlaybackSettings::LOOP.with_volume(Volume::new(music_volume(&settings))) },
+        Music(track),
+    ));
+}
+
+// Volume de la musique appliqué en direct depuis les options
+fn update_music_volume(settings: Res<Settings>, sinks: Query<&AudioSink, With<Music>>) {
+    if !settings.is_changed() { return; }
+    for sink in sinks.iter() {
+        sink.set_volume(music_volume(&settings));
+    }
+}
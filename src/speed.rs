@@ -0,0 +1,152 @@
+// src/speed.rs
+// Vitesse du jeu : pause, x1, x2, x4 et avance image par image (debug)
+// Tout passe par Time<Virtual> : mouvements, cooldowns, projectiles et timers de spawn
+// sont donc accélérés de la même façon (la durée de la Simulation, elle, est comptée en temps réel)
+
+use bevy::{prelude::*, time::TimeSystem};
+use serde::{Serialize, Deserialize};
+use crate::{AppState, input::{ActionState, InputAction}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SpeedSetting {
+    #[default]
+    Normal,
+    Double,
+    Quadruple,
+}
+
+impl SpeedSetting {
+    pub const ALL: [SpeedSetting; 3] = [SpeedSetting::Normal, SpeedSetting::Double, SpeedSetting::Quadruple];
+
+    pub fn multiplier(&self) -> f32 {
+        match self {
+            SpeedSetting::Normal => 1.0,
+            SpeedSetting::Double => 2.0,
+            SpeedSetting::Quadruple => 4.0,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SpeedSetting::Normal => "1x",
+            SpeedSetting::Double => "2x",
+            SpeedSetting::Quadruple => "4x",
+        }
+    }
+
+    pub fn next(&self) -> SpeedSetting {
+        match self {
+            SpeedSetting::Normal => SpeedSetting::Double,
+            SpeedSetting::Double => SpeedSetting::Quadruple,
+            SpeedSetting::Quadruple => SpeedSetting::Normal,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct GameSpeed {
+    pub paused: bool,
+    pub setting: SpeedSetting,
+    step_requested: bool,
+    stepping: bool,
+}
+
+impl GameSpeed {
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    // Fait avancer le jeu d'une seule frame (uniquement en pause)
+    pub fn request_step(&mut self) {
+        if self.paused { self.step_requested = true; }
+    }
+
+    // Le gameplay tourne cette frame (hors pause, ou pendant une avance image par image)
+    pub fn is_running(&self) -> bool {
+        !self.paused || self.stepping
+    }
+
+    pub fn reset(&mut self, setting: SpeedSetting) {
+        *self = GameSpeed { setting, ..default() };
+    }
+}
+
+// Condition d'exécution des systèmes de gameplay
+pub fn gameplay_running(speed: Res<GameSpeed>) -> bool {
+    speed.is_running()
+}
+
+// --- Boutons du HUD ---
+#[derive(Component)]
+pub struct BtnSpeed(pub SpeedSetting);
+#[derive(Component)]
+pub struct BtnStep;
+
+pub struct GameSpeedPlugin;
+
+impl Plugin for GameSpeedPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GameSpeed>()
+            // Avant la mise à jour du temps, pour que la frame courante soit déjà à la bonne vitesse
+            .add_systems(First, apply_game_speed.before(TimeSystem))
+            .add_systems(Update, (speed_actions, speed_button_interaction, update_speed_buttons)
+                .run_if(in_state(AppState::Playing).or_else(in_state(AppState::Simulation))));
+    }
+}
+
+fn apply_game_speed(mut speed: ResMut<GameSpeed>, mut time: ResMut<Time<Virtual>>) {
+    // On n'écrit que si nécessaire pour garder la détection de changement utile
+    let stepping = speed.paused && speed.step_requested;
+    if speed.stepping != stepping || speed.step_requested {
+        speed.stepping = stepping;
+        speed.step_requested = false;
+    }
+
+    time.set_relative_speed(speed.setting.multiplier());
+    if speed.is_running() { time.unpause(); } else { time.pause(); }
+}
+
+fn speed_actions(actions: Res<ActionState>, mut speed: ResMut<GameSpeed>) {
+    if actions.just_pressed(InputAction::SpeedUp) { speed.setting = speed.setting.next(); }
+    if actions.just_pressed(InputAction::FrameStep) { speed.request_step(); }
+}
+
+// Petits boutons 1x / 2x / 4x / pas-à-pas sous le bouton PAUSE
+pub fn spawn_speed_buttons(parent: &mut ChildBuilder) {
+    parent.spawn(NodeBundle { style: Style { margin: UiRect::top(Val::Px(4.0)), ..default() }, ..default() })
+        .with_children(|row| {
+            for setting in SpeedSetting::ALL {
+                spawn_small_button(row, setting.label(), BtnSpeed(setting));
+            }
+            spawn_small_button(row, ">|", BtnStep);
+        });
+}
+
+fn spawn_small_button<T: Component>(parent: &mut ChildBuilder, text: &str, marker: T) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style { width: Val::Px(19.0), height: Val::Px(20.0), margin: UiRect::right(Val::Px(1.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(1.0)), ..default() },
+            background_color: Color::rgb(0.33, 0.23, 0.15).into(), border_color: BorderColor(Color::BLACK), ..default()
+        }, marker
+    )).with_children(|p| { p.spawn(TextBundle::from_section(text, TextStyle { font_size: 11.0, color: Color::rgb(0.9, 0.85, 0.7), ..default() })); });
+}
+
+fn speed_button_interaction(
+    query: Query<(&Interaction, Option<&BtnSpeed>, Option<&BtnStep>), (Changed<Interaction>, With<Button>)>,
+    mut speed: ResMut<GameSpeed>,
+) {
+    for (interaction, btn_speed, btn_step) in query.iter() {
+        if *interaction != Interaction::Pressed { continue; }
+        if let Some(btn) = btn_speed { speed.setting = btn.0; }
+        if btn_step.is_some() { speed.request_step(); }
+    }
+}
+
+// Bordure blanche sur la vitesse active
+fn update_speed_buttons(speed: Res<GameSpeed>, mut query: Query<(&BtnSpeed, &mut BorderColor)>) {
+    if !speed.is_changed() { return; }
+    for (btn, mut border) in query.iter_mut() {
+        *border = BorderColor(if btn.0 == speed.setting { Color::WHITE } else { Color::BLACK });
+    }
+}
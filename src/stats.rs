@@ -0,0 +1,142 @@
+// src/stats.rs
+// Écran des statistiques du profil (AppState::Stats)
+
+use bevy::prelude::*;
+use crate::{
+    AppState,
+    level,
+    profile::PlayerProfile,
+    tower::TowerType,
+    constants::enemies as EnemyConstants,
+    i18n::Localization,
+    menu::{NORMAL_BUTTON, HOVERED_BUTTON, PRESSED_BUTTON},
+};
+
+#[derive(Component)]
+struct StatsUI;
+
+#[derive(Component)]
+struct BtnBack;
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::Stats), setup_stats)
+            .add_systems(Update, back_button_interaction.run_if(in_state(AppState::Stats)))
+            .add_systems(OnExit(AppState::Stats), cleanup_stats);
+    }
+}
+
+// 3725 s -> "1h 02m 05s"
+fn format_play_time(seconds: f64) -> String {
+    let total = seconds as u64;
+    format!("{}h {:02}m {:02}s", total / 3600, (total % 3600) / 60, total % 60)
+}
+
+fn setup_stats(mut commands: Commands, profile: Res<PlayerProfile>, loc: Res<Localization>) {
+    let title_style = TextStyle { font_size: 36.0, color: Color::WHITE, ..default() };
+    let header_style = TextStyle { font_size: 22.0, color: Color::rgb(0.9, 0.75, 0.4), ..default() };
+    let text_style = TextStyle { font_size: 18.0, color: Color::WHITE, ..default() };
+
+    // Lignes "libellé ....... valeur" regroupées par section
+    let best = profile.best.get(level::LEVEL_NAME).copied().unwrap_or_default();
+    let sections: Vec<(&str, Vec<(String, String)>)> = vec![
+        ("stats.general", vec![
+            (loc.get("stats.play_time").into(), format_play_time(profile.play_time)),
+            (loc.get("stats.total_kills").into(), profile.total_kills().to_string()),
+        ]),
+        ("stats.best_run", vec![
+            (loc.get("stats.score").into(), best.score.to_string()),
+            (loc.get("stats.waves_survived").into(), best.waves_survived.to_string()),
+            (loc.get("stats.lives_remaining").into(), best.lives.to_string()),
+            (loc.get("stats.gold").into(), best.gold.to_string()),
+        ]),
+        ("stats.kills", [EnemyConstants::ORC, EnemyConstants::BAT, EnemyConstants::KNIGHT, EnemyConstants::WOLF].iter()
+            .map(|t| (loc.get(EnemyConstants::get_name_key(*t)).to_string(), profile.kills.get(t).copied().unwrap_or(0).to_string()))
+            .collect()),
+        ("stats.towers_built", [TowerType::Canon, TowerType::Archer, TowerType::Wizard].iter()
+            .map(|t| (loc.get(t.name_key()).to_string(), profile.towers_built.get(t).copied().unwrap_or(0).to_string()))
+            .collect()),
+        ("stats.high_scores", profile.high_scores.iter().take(5).enumerate()
+            .map(|(i, h)| {
+                let result = loc.get(if h.victory { "stats.victory" } else { "stats.defeat" });
+                (loc.format("stats.high_score", &[&(i + 1), &result, &h.waves]), h.score.to_string())
+            })
+            .collect()),
+    ];
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.1, 0.1, 0.1).into(),
+            ..default()
+        },
+        StatsUI,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(loc.get("stats.title"), title_style)
+            .with_style(Style { margin: UiRect::bottom(Val::Px(8.0)), ..default() }));
+
+        for (header, rows) in sections {
+            parent.spawn(TextBundle::from_section(loc.get(header), header_style.clone())
+                .with_style(Style { margin: UiRect::vertical(Val::Px(4.0)), ..default() }));
+            for (label, value) in rows {
+                parent.spawn(NodeBundle {
+                    style: Style { width: Val::Px(300.0), justify_content: JustifyContent::SpaceBetween, ..default() },
+                    ..default()
+                }).with_children(|row| {
+                    row.spawn(TextBundle::from_section(label, text_style.clone()));
+                    row.spawn(TextBundle::from_section(value, text_style.clone()));
+                });
+            }
+        }
+
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(150.0),
+                    height: Val::Px(36.0),
+                    margin: UiRect::top(Val::Px(20.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            BtnBack,
+        )).with_children(|b| {
+            b.spawn(TextBundle::from_section(loc.get("button.back"), TextStyle { font_size: 20.0, color: Color::WHITE, ..default() }));
+        });
+    });
+}
+
+fn back_button_interaction(
+    mut query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<BtnBack>)>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut color) in query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                next_state.set(AppState::Menu);
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+fn cleanup_stats(mut commands: Commands, query: Query<Entity, With<StatsUI>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,65 @@
+// src/storage.rs
+// Lecture / écriture des fichiers du joueur (config, sauvegardes...) au format RON
+
+use std::{env, fs, path::PathBuf};
+use serde::{Serialize, de::DeserializeOwned};
+
+const APP_DIR: &str = "ECSTowerDefense";
+
+// Dossier de configuration de l'utilisateur (~/.config/ECSTowerDefense sous Linux)
+pub fn config_dir() -> PathBuf {
+    let base = if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|h| PathBuf::from(h).join("Library/Application Support"))
+    } else {
+        env::var_os("XDG_CONFIG_HOME").map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+    };
+    base.unwrap_or_else(|| PathBuf::from(".")).join(APP_DIR)
+}
+
+// Dossier de données de l'utilisateur (~/.local/share/ECSTowerDefense sous Linux)
+pub fn data_dir() -> PathBuf {
+    let base = if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|h| PathBuf::from(h).join("Library/Application Support"))
+    } else {
+        env::var_os("XDG_DATA_HOME").map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))
+    };
+    base.unwrap_or_else(|| PathBuf::from(".")).join(APP_DIR)
+}
+
+// Charge un fichier RON, None s'il n'existe pas ou s'il est invalide
+pub fn load_ron<T: DeserializeOwned>(path: &PathBuf) -> Option<T> {
+    let content = fs::read_to_string(path).ok()?;
+    match ron::from_str(&content) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            println!("Fichier {} invalide, ignoré : {}", path.display(), e);
+            None
+        }
+    }
+}
+
+// Écrit un fichier RON (crée le dossier si besoin)
+pub fn save_ron<T: Serialize>(path: &PathBuf, value: &T) {
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    let result = ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+        .map_err(|e| e.to_string())
+        .and_then(|content| fs::write(path, content).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        println!("Impossible d'écrire {} : {}", path.display(), e);
+    }
+}
+
+// Supprime un fichier du joueur (sans erreur s'il n'existe pas)
+pub fn remove(path: &PathBuf) {
+    if path.exists() && let Err(e) = fs::remove_file(path) {
+        println!("Impossible de supprimer {} : {}", path.display(), e);
+    }
+}
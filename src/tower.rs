@@ -1,7 +1,10 @@
+use std::{collections::BTreeMap, ops::AddAssign};
 use bevy::prelude::*;
+use serde::{Serialize, Deserialize};
+use crate::constants::projectiles;
 
 // Les 3 types de tours dans le jeu
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Component, Serialize, Deserialize)]
 pub enum TowerType {
     Canon = 0,
     Archer = 1,
@@ -14,9 +17,73 @@ pub struct Tower {
     pub range: f32,
     pub damage: i32,
     pub cooldown: Timer,
+    pub tier: u32, // 1 à MAX_TIER
+}
+
+// Bilan d'une tour posée (panneau de la tour sélectionnée, écran de fin)
+// Les impacts sont attribués à la tour via la source du projectile
+#[derive(Component, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TowerStats {
+    pub kills: u32,
+    pub damage: i64, // Dégâts réellement infligés (sans l'excédent sur un ennemi achevé)
+    pub shots: u32,
+}
+
+impl AddAssign for TowerStats {
+    fn add_assign(&mut self, other: Self) {
+        self.kills += other.kills;
+        self.damage += other.damage;
+        self.shots += other.shots;
+    }
+}
+
+// Bilan des tours vendues pendant la partie, par type : (nombre de tours, stats cumulées)
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SoldTowerStats(pub BTreeMap<TowerType, (u32, TowerStats)>);
+
+impl SoldTowerStats {
+    pub fn add(&mut self, tower_type: TowerType, stats: TowerStats) {
+        let entry = self.0.entry(tower_type).or_default();
+        entry.0 += 1;
+        entry.1 += stats;
+    }
+
+    // Tir ou impact d'un projectile arrivé après la vente de sa tour
+    pub fn credit(&mut self, tower_type: TowerType, stats: TowerStats) {
+        self.0.entry(tower_type).or_default().1 += stats;
+    }
+}
+
+// Niveau maximum d'une tour (2 améliorations comme en Java)
+pub const MAX_TIER: u32 = 3;
+
+impl Tower {
+    pub fn new(tower_type: TowerType, tier: u32) -> Self {
+        let (range, damage, cooldown) = tower_type.get_stats(tier);
+        Tower { range, damage, cooldown: Timer::from_seconds(cooldown, TimerMode::Repeating), tier }
+    }
+
+    // Passe au niveau supérieur en gardant la progression du cooldown
+    pub fn upgrade(&mut self, tower_type: TowerType) {
+        self.tier = (self.tier + 1).min(MAX_TIER);
+        let (range, damage, cooldown) = tower_type.get_stats(self.tier);
+        self.range = range;
+        self.damage = damage;
+        self.cooldown.set_duration(std::time::Duration::from_secs_f32(cooldown));
+    }
 }
 
 impl TowerType {
+    // Clé du nom traduit (i18n.rs)
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            TowerType::Canon => "tower.canon",
+            TowerType::Archer => "tower.archer",
+            TowerType::Wizard => "tower.wizard",
+        }
+    }
+
     // Valeurs tirées de Constants.java
     // Canon: Dmg 15, Range 75, CD 120 ticks (2.0s à 60 UPS) -> Bevy Timer 2.0s
     // Archer: Dmg 5, Range 120, CD 35 ticks (0.58s)
@@ -43,6 +110,28 @@ impl TowerType {
         }
     }
 
+    // Stats à un niveau donné : tier 1 = base, tier 3 = stats de simulation, tier 2 entre les deux
+    pub fn get_stats(&self, tier: u32) -> (f32, i32, f32) {
+        let (b_range, b_damage, b_cd) = self.get_base_stats();
+        let (m_range, m_damage, m_cd) = self.get_sim_stats();
+        let t = (tier.clamp(1, MAX_TIER) - 1) as f32 / (MAX_TIER - 1) as f32;
+        (
+            b_range + (m_range - b_range) * t,
+            b_damage + ((m_damage - b_damage) as f32 * t).round() as i32,
+            b_cd + (m_cd - b_cd) * t,
+        )
+    }
+
+    // Java : une amélioration coûte 30% du prix de la tour
+    pub fn get_upgrade_cost(&self) -> i32 {
+        (self.get_cost() as f32 * 0.3) as i32
+    }
+
+    // Java : on récupère la moitié de ce qui a été investi (tour + améliorations)
+    pub fn get_sell_value(&self, tier: u32) -> i32 {
+        (self.get_cost() + self.get_upgrade_cost() * (tier as i32 - 1)) / 2
+    }
+
     pub fn get_cost(&self) -> i32 {
         match self {
             TowerType::Canon => 65,
@@ -51,11 +140,21 @@ impl TowerType {
         }
     }
 
-    pub fn get_sprite_index(&self) -> usize {
+    // Équivalent de getProjectileType() en Java
+    pub fn get_projectile_type(&self) -> u32 {
+        match self {
+            TowerType::Canon => projectiles::BOMB,
+            TowerType::Archer => projectiles::ARROW,
+            TowerType::Wizard => projectiles::CHAINS,
+        }
+    }
+
+    // Nom du sprite dans l'atlas
+    pub fn sprite_name(&self) -> &'static str {
         match self {
-            TowerType::Canon => 14,
-            TowerType::Archer => 15,
-            TowerType::Wizard => 16,
+            TowerType::Canon => "canon",
+            TowerType::Archer => "archer",
+            TowerType::Wizard => "wizard",
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,315 @@
+// src/wave.rs
+// Vagues d'ennemis (équivalent de WaveManager.java) : une courte pause, puis les ennemis
+// de la vague apparaissent un par un ; la vague suivante démarre quand tout est mort ou passé
+// En mode Endless les vagues sont générées à partir d'une graine (seed), sans fin
+
+use bevy::prelude::*;
+use crate::{
+    AppState,
+    atlas::SpriteAtlas,
+    speed::gameplay_running,
+    game::Path,
+    enemy::{Enemy, Health, base_speed, spawn_enemy},
+    difficulty::Difficulty,
+    boss::{BossKind, spawn_boss},
+    input::{ActionState, InputAction},
+    core::{WaveStarted, WaveCleared},
+    i18n::Localized,
+    constants::enemies::{self as EnemyConstants, ORC, BAT, KNIGHT, WOLF},
+};
+
+// Composition des vagues : (type d'ennemi, nombre), dans l'ordre d'apparition
+const WAVES: &[&[(u32, usize)]] = &[
+    &[(ORC, 8)],
+    &[(ORC, 6), (BAT, 4)],
+    &[(BAT, 6), (ORC, 6)],
+    &[(KNIGHT, 2), (ORC, 8)],
+    &[(WOLF, 6), (BAT, 6)],
+    &[(KNIGHT, 4), (WOLF, 6), (ORC, 6)],
+    &[(BAT, 10), (KNIGHT, 4)],
+    &[(WOLF, 10), (KNIGHT, 6), (BAT, 6)],
+];
+
+// Délai entre deux ennemis d'une vague et pause entre deux vagues (en secondes)
+const SPAWN_INTERVAL: f32 = 1.0;
+const WAVE_BREAK: f32 = 5.0;
+
+// --- Vagues générées (mode Endless) ---
+const ENDLESS_BASE_COUNT: usize = 8;     // Ennemis de la première vague
+const ENDLESS_COUNT_PER_WAVE: usize = 2; // Ennemis en plus à chaque vague
+const ENDLESS_HEALTH_GROWTH: f32 = 1.08; // Vie multipliée par 1.08^vague
+// Types débloqués au fil des vagues : (première vague, type)
+const ENDLESS_UNLOCKS: &[(usize, u32)] = &[(0, ORC), (2, BAT), (4, WOLF), (6, KNIGHT)];
+// Une vague de boss toutes les N vagues (boss.rs) ; en mode normal, seule la dernière vague a un boss
+const BOSS_WAVE_INTERVAL: usize = 5;
+
+// Graine demandée pour la prochaine partie Endless ("SAME SEED" sur l'écran de fin)
+#[derive(Resource)]
+pub struct ReplaySeed(pub u64);
+
+#[derive(Resource)]
+pub struct WaveManager {
+    pub current: usize,     // Indice de la vague en cours (0 = première)
+    pub spawned: usize,     // Ennemis déjà lancés dans cette vague
+    pub spawn_timer: Timer,
+    pub break_timer: Timer,
+    pub in_break: bool,     // Pause avant le début de la vague `current`
+    pub seed: Option<u64>,  // Some = vagues générées (mode Endless)
+    early_call: Option<f32>, // Temps de pause restant quand le joueur a appelé la vague
+}
+
+impl Default for WaveManager {
+    fn default() -> Self {
+        Self {
+            current: 0,
+            spawned: 0,
+            spawn_timer: Timer::from_seconds(SPAWN_INTERVAL, TimerMode::Repeating),
+            break_timer: Timer::from_seconds(WAVE_BREAK, TimerMode::Once),
+            in_break: true,
+            seed: None,
+            early_call: None,
+        }
+    }
+}
+
+impl WaveManager {
+    pub fn endless(seed: u64) -> Self {
+        Self { seed: Some(seed), ..Self::default() }
+    }
+
+    // Nombre total de vagues (None en mode Endless)
+    pub fn wave_count(&self) -> Option<usize> {
+        match self.seed {
+            Some(_) => None,
+            None => Some(WAVES.len()),
+        }
+    }
+
+    // Numéro affiché (1 = première vague)
+    pub fn wave_number(&self) -> usize {
+        match self.wave_count() {
+            Some(count) => (self.current + 1).min(count),
+            None => self.current + 1,
+        }
+    }
+
+    // "3/8", ou "3" en mode Endless
+    pub fn wave_label(&self) -> String {
+        match self.wave_count() {
+            Some(count) => format!("{}/{}", self.wave_number(), count),
+            None => self.wave_number().to_string(),
+        }
+    }
+
+    // Toutes les vagues ont été lancées et nettoyées (jamais en mode Endless)
+    pub fn is_finished(&self) -> bool {
+        self.wave_count().is_some_and(|count| self.current >= count)
+    }
+
+    // Boss qui ferme la vague, s'il y en a un (les archétypes tournent d'une vague de boss à l'autre)
+    pub fn boss_of(&self, wave: usize) -> Option<BossKind> {
+        match self.seed {
+            Some(_) if (wave + 1).is_multiple_of(BOSS_WAVE_INTERVAL) => Some(BossKind::ALL[((wave + 1) / BOSS_WAVE_INTERVAL - 1) % BossKind::ALL.len()]),
+            Some(_) => None,
+            None => (wave + 1 == WAVES.len()).then_some(BossKind::Warlord),
+        }
+    }
+
+    // Composition de la vague : (type d'ennemi, nombre), sans le boss
+    fn composition(&self, wave: usize) -> Vec<(u32, usize)> {
+        match self.seed {
+            Some(seed) => generate_wave(seed, wave),
+            None => WAVES[wave].to_vec(),
+        }
+    }
+
+    fn wave_size(&self, wave: usize) -> usize {
+        self.composition(wave).iter().map(|(_, count)| count).sum::<usize>() + self.boss_of(wave).iter().count()
+    }
+
+    // Termine la pause tout de suite en retenant le temps économisé
+    pub fn call_next_wave(&mut self) {
+        if !self.in_break || self.is_finished() || self.break_timer.finished() { return; }
+        self.early_call = Some(self.break_timer.remaining_secs());
+        let remaining = self.break_timer.remaining();
+        self.break_timer.tick(remaining);
+    }
+
+    // n-ième ennemi de la vague (le boss éventuel arrive en dernier)
+    fn enemy_at(&self, wave: usize, index: usize) -> WaveEnemy {
+        let mut remaining = index;
+        for (enemy_type, count) in self.composition(wave) {
+            if remaining < count { return WaveEnemy::Regular(enemy_type); }
+            remaining -= count;
+        }
+        match self.boss_of(wave) {
+            Some(kind) => WaveEnemy::Boss(kind),
+            None => WaveEnemy::Regular(ORC),
+        }
+    }
+
+    // Vie d'un ennemi de la vague : difficulté, puis croissance des vagues Endless
+    fn enemy_health(&self, difficulty: &Difficulty, wave: usize, base: u32) -> i32 {
+        let health = difficulty.enemy_health(base) as f32;
+        match self.seed {
+            Some(_) => (health * ENDLESS_HEALTH_GROWTH.powi(wave as i32)).round() as i32,
+            None => health as i32,
+        }
+    }
+}
+
+enum WaveEnemy {
+    Regular(u32),
+    Boss(BossKind),
+}
+
+// Vague Endless : même graine + même numéro = même vague (la sauvegarde ne garde que la graine)
+fn generate_wave(seed: u64, wave: usize) -> Vec<(u32, usize)> {
+    let mut rng = fastrand::Rng::with_seed(seed ^ (wave as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    let types: Vec<u32> = ENDLESS_UNLOCKS.iter().filter(|(from, _)| wave >= *from).map(|(_, t)| *t).collect();
+    let mut remaining = ENDLESS_BASE_COUNT + ENDLESS_COUNT_PER_WAVE * wave;
+    // Groupes de 2 à 6 ennemis d'un même type
+    let mut groups = Vec::new();
+    while remaining > 0 {
+        let count = rng.usize(2..=6).min(remaining);
+        groups.push((types[rng.usize(..types.len())], count));
+        remaining -= count;
+    }
+    groups
+}
+
+// Bouton "appeler la vague suivante" du HUD
+#[derive(Component)]
+pub struct BtnCallWave;
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        // Le WaveManager est créé par setup_game (et remplacé au chargement d'une partie)
+        app
+            .add_systems(Update, (call_wave_input, update_waves).chain().run_if(in_state(AppState::Playing).and_then(gameplay_running)))
+            .add_systems(Update, update_call_wave_button.run_if(in_state(AppState::Playing)));
+    }
+}
+
+pub fn spawn_call_wave_button(parent: &mut ChildBuilder) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style { width: Val::Px(80.0), height: Val::Px(20.0), margin: UiRect::top(Val::Px(4.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(1.0)), ..default() },
+            background_color: Color::rgb(0.33, 0.23, 0.15).into(), border_color: BorderColor(Color::BLACK), ..default()
+        }, BtnCallWave
+    )).with_children(|p| { p.spawn((TextBundle::from_section("", TextStyle { font_size: 11.0, color: Color::rgb(0.9, 0.85, 0.7), ..default() }), Localized("button.next_wave"))); });
+}
+
+// Appel anticipé de la vague (touche ou bouton), récompensé par le score
+fn call_wave_input(
+    actions: Res<ActionState>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<BtnCallWave>)>,
+    mut waves: ResMut<WaveManager>,
+) {
+    if actions.just_pressed(InputAction::CallWave) || buttons.iter().any(|i| *i == Interaction::Pressed) {
+        waves.call_next_wave();
+    }
+}
+
+// Le bouton n'est actif que pendant la pause entre deux vagues
+fn update_call_wave_button(waves: Res<WaveManager>, mut query: Query<&mut Visibility, With<BtnCallWave>>) {
+    for mut vis in query.iter_mut() {
+        let target = if waves.in_break && !waves.is_finished() { Visibility::Inherited } else { Visibility::Hidden };
+        if *vis != target { *vis = target; }
+    }
+}
+
+pub fn update_waves(
+    mut commands: Commands,
+    atlas: Res<SpriteAtlas>,
+    path: Res<Path>,
+    difficulty: Res<Difficulty>,
+    time: Res<Time>,
+    mut waves: ResMut<WaveManager>,
+    enemies: Query<(), With<Enemy>>,
+    mut started: EventWriter<WaveStarted>,
+    mut cleared: EventWriter<WaveCleared>,
+) {
+    if waves.is_finished() || path.points.is_empty() { return; }
+
+    if waves.in_break {
+        waves.break_timer.tick(time.delta());
+        if waves.break_timer.finished() {
+            waves.in_break = false;
+            waves.spawned = 0;
+            waves.spawn_timer.reset();
+            let early_seconds = waves.early_call.take().unwrap_or(0.0);
+            started.send(WaveStarted { wave: waves.current, early_seconds });
+        }
+        return;
+    }
+
+    let wave = waves.current;
+    let size = waves.wave_size(wave);
+    if waves.spawned < size {
+        waves.spawn_timer.tick(time.delta());
+        if waves.spawn_timer.just_finished() {
+            match waves.enemy_at(wave, waves.spawned) {
+                WaveEnemy::Regular(enemy_type) => {
+                    let max = waves.enemy_health(&difficulty, wave, EnemyConstants::get_starthealth(enemy_type));
+                    let speed = difficulty.enemy_speed(base_speed(enemy_type));
+                    spawn_enemy(&mut commands, &atlas, enemy_type, path.points[0], 1, Health { current: max, max }, speed);
+                }
+                WaveEnemy::Boss(kind) => {
+                    let max = waves.enemy_health(&difficulty, wave, kind.base_health());
+                    let speed = difficulty.enemy_speed(kind.base_speed());
+                    spawn_boss(&mut commands, &atlas, kind, path.points[0], 1, Health { current: max, max }, speed);
+                }
+            }
+            waves.spawned += 1;
+        }
+    } else if enemies.is_empty() {
+        // Vague terminée : pause puis vague suivante
+        cleared.send(WaveCleared { wave: waves.current });
+        waves.current += 1;
+        waves.in_break = true;
+        waves.break_timer.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_the_same_waves() {
+        for wave in 0..20 {
+            assert_eq!(generate_wave(42, wave), generate_wave(42, wave));
+        }
+        assert!((0..20).any(|wave| generate_wave(42, wave) != generate_wave(43, wave)));
+    }
+
+    #[test]
+    fn generated_waves_use_unlocked_types_and_grow() {
+        for wave in 0..20 {
+            let groups = generate_wave(7, wave);
+            let size: usize = groups.iter().map(|(_, count)| count).sum();
+            assert_eq!(size, ENDLESS_BASE_COUNT + ENDLESS_COUNT_PER_WAVE * wave);
+            for (enemy_type, _) in groups {
+                assert!(ENDLESS_UNLOCKS.iter().any(|&(from, t)| t == enemy_type && wave >= from));
+            }
+        }
+    }
+
+    #[test]
+    fn endless_boss_every_interval() {
+        let waves = WaveManager::endless(7);
+        for wave in 0..4 * BOSS_WAVE_INTERVAL {
+            let boss = waves.boss_of(wave);
+            assert_eq!(boss.is_some(), (wave + 1).is_multiple_of(BOSS_WAVE_INTERVAL), "vague {}", wave + 1);
+            // Le boss arrive en dernier dans sa vague
+            let last = waves.enemy_at(wave, waves.wave_size(wave) - 1);
+            assert_eq!(matches!(last, WaveEnemy::Boss(kind) if Some(kind) == boss), boss.is_some());
+        }
+        // Les archétypes tournent d'une vague de boss à l'autre
+        let bosses: Vec<_> = (1..=BossKind::ALL.len()).map(|n| waves.boss_of(n * BOSS_WAVE_INTERVAL - 1).unwrap()).collect();
+        assert_eq!(bosses, BossKind::ALL);
+    }
+}